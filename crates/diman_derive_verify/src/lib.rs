@@ -22,7 +22,8 @@ pub fn derive_verify(input: proc_macro::TokenStream) -> proc_macro::TokenStream
         impl #impl_generics crate::verify::Verify for #name #ty_generics #where_clause {
             type Verified = #verified_type;
             fn verify(self) -> Result<Self::Verified> {
-                Ok(#verify_impl)
+                let mut errors = crate::verify::Errors::default();
+                #verify_impl
             }
         }
     }
@@ -56,38 +57,31 @@ fn gen_verify_impl(data: &Data, t: &Path) -> TokenStream {
     match *data {
         Data::Struct(ref data) => match data.fields {
             Fields::Named(ref fields) => {
-                let fields: TokenStream = fields
-                    .named
-                    .iter()
-                    .map(|f| {
-                        let name = &f.ident;
-                        quote! {
-                            #name: self.#name.verify()?,
-                        }
-                    })
-                    .collect();
+                let names: Vec<_> = fields.named.iter().map(|f| &f.ident).collect();
                 quote! {
-                    #t { #fields }
+                    #(
+                        let #names = errors.add(self.#names.verify());
+                    )*
+                    errors.finish()?;
+                    Ok(#t { #( #names: #names.unwrap(), )* })
                 }
             }
             Fields::Unnamed(ref fields) => {
-                let fields: TokenStream = fields
-                    .unnamed
+                let indices: Vec<_> = (0..fields.unnamed.len()).map(Index::from).collect();
+                let names: Vec<_> = indices
                     .iter()
-                    .enumerate()
-                    .map(|(i, _)| {
-                        let index = Index::from(i);
-                        quote! {
-                            &self.#index.verify()?,
-                        }
-                    })
+                    .map(|index| format_ident!("field{}", index))
                     .collect();
                 quote! {
-                    #t ( #fields )
+                    #(
+                        let #names = errors.add(self.#indices.verify());
+                    )*
+                    errors.finish()?;
+                    Ok(#t ( #( #names.unwrap(), )* ))
                 }
             }
             Fields::Unit => {
-                quote! {}
+                quote! { Ok(#t) }
             }
         },
         Data::Enum(ref data) => {
@@ -114,12 +108,27 @@ fn gen_verify_impl(data: &Data, t: &Path) -> TokenStream {
                         .map(|(i, _)| {
                             let ident = format_ident!("field{}", i);
                             quote! {
-                                #ident.verify()?,
+                                errors.add(#ident.verify()),
+                            }
+                        })
+                        .collect();
+                    let fields_unwrap: TokenStream = variant
+                        .fields
+                        .iter()
+                        .enumerate()
+                        .map(|(i, _)| {
+                            let ident = format_ident!("field{}", i);
+                            quote! {
+                                #ident.unwrap(),
                             }
                         })
                         .collect();
                     quote! {
-                        Self::#ident(#fields_match) => #t::#ident(#fields_cons),
+                        Self::#ident(#fields_match) => {
+                            let (#fields_match) = (#fields_cons);
+                            errors.finish()?;
+                            Ok(#t::#ident(#fields_unwrap))
+                        }
                     }
                 })
                 .collect();
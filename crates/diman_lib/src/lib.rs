@@ -0,0 +1,7 @@
+#![allow(incomplete_features)]
+#![feature(adt_const_params)]
+//! Types which are used both by diman and by its procedural macros,
+//! so that the macros perform exactly the same arithmetic as the
+//! generated code.
+
+pub mod ratio;
@@ -0,0 +1,281 @@
+/// A rational number which can be used as the type of the fields
+/// of a dimension struct, allowing for fractional exponents
+/// such as in `V / Hz^1/2`.
+/// The ratio is always stored in lowest terms with a positive
+/// denominator, so that structural equality (as required for
+/// const generics) coincides with numerical equality.
+#[derive(Clone, Copy, PartialEq, Eq, std::marker::ConstParamTy)]
+pub struct Ratio {
+    numerator: i64,
+    denominator: i64,
+}
+
+/// The greatest common divisor, as `u64` since it is `2^63`
+/// for `gcd(i64::MIN, i64::MIN)`.
+const fn gcd(a: i64, b: i64) -> u64 {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+    while b != 0 {
+        let t = b;
+        b = a % b;
+        a = t;
+    }
+    a
+}
+
+// These are const fns so that they can be used in the methods of
+// dimension types, so they cannot be implemented via the traits in std::ops.
+#[allow(clippy::should_implement_trait)]
+impl Ratio {
+    pub const fn new(numerator: i64, denominator: i64) -> Self {
+        if denominator == 0 {
+            panic!("Denominator of a ratio cannot be zero.");
+        }
+        match Self::reduce(numerator, denominator) {
+            Some(ratio) => ratio,
+            None => panic!("Overflow when reducing a ratio."),
+        }
+    }
+
+    /// Reduce the ratio to lowest terms with a positive denominator,
+    /// returning `None` if the result is not representable, as for
+    /// `1 / i64::MIN`. The reduction is performed in `i128`, so that
+    /// it does not overflow for `i64::MIN`. The denominator must not be zero.
+    const fn reduce(numerator: i64, denominator: i64) -> Option<Self> {
+        let divisor = gcd(numerator, denominator) as i128 * denominator.signum() as i128;
+        let numerator = numerator as i128 / divisor;
+        let denominator = denominator as i128 / divisor;
+        if numerator < i64::MIN as i128 || numerator > i64::MAX as i128 {
+            return None;
+        }
+        if denominator > i64::MAX as i128 {
+            return None;
+        }
+        Some(Self {
+            numerator: numerator as i64,
+            denominator: denominator as i64,
+        })
+    }
+
+    pub const fn int(numerator: i64) -> Self {
+        Self {
+            numerator,
+            denominator: 1,
+        }
+    }
+
+    pub const fn numerator(&self) -> i64 {
+        self.numerator
+    }
+
+    pub const fn denominator(&self) -> i64 {
+        self.denominator
+    }
+
+    pub const fn is_int(&self) -> bool {
+        self.denominator == 1
+    }
+
+    pub const fn add(self, other: Self) -> Self {
+        Self::new(
+            self.numerator * other.denominator + other.numerator * self.denominator,
+            self.denominator * other.denominator,
+        )
+    }
+
+    pub const fn sub(self, other: Self) -> Self {
+        self.add(other.neg())
+    }
+
+    pub const fn mul(self, other: Self) -> Self {
+        Self::new(
+            self.numerator * other.numerator,
+            self.denominator * other.denominator,
+        )
+    }
+
+    pub const fn div(self, other: Self) -> Self {
+        Self::new(
+            self.numerator * other.denominator,
+            self.denominator * other.numerator,
+        )
+    }
+
+    pub const fn neg(self) -> Self {
+        match self.numerator.checked_neg() {
+            Some(numerator) => Self {
+                numerator,
+                denominator: self.denominator,
+            },
+            None => panic!("Overflow when negating a ratio."),
+        }
+    }
+
+    /// Reduce the given numerator and denominator, returning `None` if
+    /// either of them overflowed or the result is not representable.
+    const fn checked_new(numerator: Option<i64>, denominator: Option<i64>) -> Option<Self> {
+        match (numerator, denominator) {
+            (Some(numerator), Some(denominator)) if denominator != 0 => {
+                Self::reduce(numerator, denominator)
+            }
+            _ => None,
+        }
+    }
+
+    /// Like `add`, but returns `None` on overflow.
+    pub const fn checked_add(self, other: Self) -> Option<Self> {
+        let numerator = match (
+            self.numerator.checked_mul(other.denominator),
+            other.numerator.checked_mul(self.denominator),
+        ) {
+            (Some(a), Some(b)) => a.checked_add(b),
+            _ => None,
+        };
+        Self::checked_new(numerator, self.denominator.checked_mul(other.denominator))
+    }
+
+    /// Like `mul`, but returns `None` on overflow.
+    pub const fn checked_mul(self, other: Self) -> Option<Self> {
+        Self::checked_new(
+            self.numerator.checked_mul(other.numerator),
+            self.denominator.checked_mul(other.denominator),
+        )
+    }
+
+    /// Like `div`, but returns `None` on overflow or division by zero.
+    pub const fn checked_div(self, other: Self) -> Option<Self> {
+        Self::checked_new(
+            self.numerator.checked_mul(other.denominator),
+            self.denominator.checked_mul(other.numerator),
+        )
+    }
+
+    pub fn to_f64(self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+
+    /// Raise a non-negative base to this power. Unlike `f64::powf`,
+    /// this can be evaluated at compile time.
+    pub const fn pow_f64(self, base: f64) -> f64 {
+        powi(nth_root(base, self.denominator), self.numerator)
+    }
+}
+
+const fn powi(base: f64, exponent: i64) -> f64 {
+    let mut result = 1.0;
+    let mut power = base;
+    let mut remaining = exponent.unsigned_abs();
+    while remaining > 0 {
+        if remaining & 1 == 1 {
+            result *= power;
+        }
+        power *= power;
+        remaining >>= 1;
+    }
+    if exponent < 0 {
+        1.0 / result
+    } else {
+        result
+    }
+}
+
+/// The `n`-th root of `x`, via Newton's method. Starting above the
+/// root, the iterates decrease until they reach it.
+const fn nth_root(x: f64, n: i64) -> f64 {
+    if n == 1 || x == 0.0 || x == f64::INFINITY {
+        return x;
+    }
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    let mut root = if x > 1.0 { x } else { 1.0 };
+    loop {
+        let next = ((n - 1) as f64 * root + x / powi(root, n - 1)) / n as f64;
+        if next.is_nan() || next >= root {
+            return root;
+        }
+        root = next;
+    }
+}
+
+impl std::fmt::Display for Ratio {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_int() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+impl std::fmt::Debug for Ratio {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Ratio;
+
+    #[test]
+    fn ratios_are_reduced() {
+        assert_eq!(Ratio::new(2, 4), Ratio::new(1, 2));
+        assert_eq!(Ratio::new(3, -6), Ratio::new(-1, 2));
+        assert_eq!(Ratio::new(0, 5), Ratio::int(0));
+        assert_eq!(Ratio::new(i64::MIN, 2), Ratio::int(i64::MIN / 2));
+        assert_eq!(Ratio::new(i64::MIN, i64::MIN), Ratio::int(1));
+        assert_eq!(Ratio::new(i64::MIN, 1).numerator(), i64::MIN);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unrepresentable_ratio() {
+        Ratio::new(1, i64::MIN);
+    }
+
+    #[test]
+    fn ratio_arithmetic() {
+        let half = Ratio::new(1, 2);
+        let third = Ratio::new(1, 3);
+        assert_eq!(half.add(third), Ratio::new(5, 6));
+        assert_eq!(half.sub(third), Ratio::new(1, 6));
+        assert_eq!(half.mul(third), Ratio::new(1, 6));
+        assert_eq!(half.div(third), Ratio::new(3, 2));
+        assert_eq!(half.add(half), Ratio::int(1));
+    }
+
+    #[test]
+    fn checked_ratio_arithmetic() {
+        let half = Ratio::new(1, 2);
+        assert_eq!(half.checked_add(half), Some(Ratio::int(1)));
+        assert_eq!(half.checked_mul(Ratio::int(4)), Some(Ratio::int(2)));
+        assert_eq!(half.checked_div(Ratio::int(0)), None);
+        assert_eq!(Ratio::int(i64::MAX).checked_add(Ratio::int(1)), None);
+        assert_eq!(Ratio::new(1, i64::MAX).checked_mul(half), None);
+        assert_eq!(
+            Ratio::int(i64::MIN).checked_mul(Ratio::int(1)),
+            Some(Ratio::int(i64::MIN))
+        );
+        assert_eq!(Ratio::int(i64::MIN).checked_div(Ratio::int(-1)), None);
+        assert_eq!(Ratio::int(1).checked_div(Ratio::int(i64::MIN)), None);
+    }
+
+    #[test]
+    fn pow_f64() {
+        let assert_is_close = |x: f64, y: f64| assert!(((x - y) / y).abs() < 1e-15, "{} {}", x, y);
+        assert_eq!(Ratio::int(3).pow_f64(10.0), 1000.0);
+        assert_eq!(Ratio::int(-2).pow_f64(10.0), 0.01);
+        assert_eq!(Ratio::int(0).pow_f64(10.0), 1.0);
+        assert_is_close(Ratio::new(1, 2).pow_f64(2.0), 2.0f64.sqrt());
+        assert_is_close(Ratio::new(-3, 2).pow_f64(1e-3), 1e-3f64.powf(-1.5));
+        assert_is_close(Ratio::new(2, 3).pow_f64(1e7), 1e7f64.powf(2.0 / 3.0));
+        assert_eq!(Ratio::new(1, 2).pow_f64(0.0), 0.0);
+        assert!(Ratio::new(1, 2).pow_f64(-1.0).is_nan());
+    }
+
+    #[test]
+    fn display_ratio() {
+        assert_eq!(Ratio::new(-1, 2).to_string(), "-1/2");
+        assert_eq!(Ratio::int(3).to_string(), "3");
+    }
+}
@@ -0,0 +1,104 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{storage_types::ArrayType, types::Defs};
+
+impl Defs {
+    pub fn array_methods(&self) -> TokenStream {
+        self.array_types()
+            .iter()
+            .map(|array_type| self.impl_array_methods(array_type))
+            .collect()
+    }
+
+    pub fn impl_array_methods(&self, array_type: &ArrayType) -> TokenStream {
+        let Defs {
+            dimension_type,
+            quantity_type,
+            ..
+        } = self;
+        let array_type_name = &array_type.name;
+        let float_type = &array_type.float_type.name;
+        quote! {
+            impl<Ix: ::ndarray::Dimension, const D: #dimension_type> #quantity_type<#array_type_name, D> {
+                pub fn len(&self) -> usize {
+                    self.0.len()
+                }
+
+                pub fn is_empty(&self) -> bool {
+                    self.0.is_empty()
+                }
+
+                pub fn shape(&self) -> &[usize] {
+                    self.0.shape()
+                }
+
+                pub fn get<I: ::ndarray::NdIndex<Ix>>(&self, index: I) -> Option<#quantity_type<#float_type, D>> {
+                    self.0.get(index).map(|value| #quantity_type(*value))
+                }
+
+                pub fn iter(&self) -> impl Iterator<Item = #quantity_type<#float_type, D>> + '_ {
+                    self.0.iter().map(|value| #quantity_type(*value))
+                }
+
+                pub fn sum(&self) -> #quantity_type<#float_type, D> {
+                    #quantity_type(self.0.sum())
+                }
+
+                /// The mean of the elements, or `None` if the array is empty.
+                pub fn mean(&self) -> Option<#quantity_type<#float_type, D>> {
+                    self.0.mean().map(#quantity_type)
+                }
+
+                /// The largest element, or `None` if the array is empty.
+                pub fn max(&self) -> Option<#quantity_type<#float_type, D>> {
+                    self.0.iter().copied().reduce(#float_type::max).map(#quantity_type)
+                }
+
+                /// The smallest element, or `None` if the array is empty.
+                pub fn min(&self) -> Option<#quantity_type<#float_type, D>> {
+                    self.0.iter().copied().reduce(#float_type::min).map(#quantity_type)
+                }
+
+                pub fn abs(&self) -> Self {
+                    Self(self.0.mapv(#float_type::abs))
+                }
+            }
+
+            impl<I, Ix, const D: #dimension_type> std::ops::Index<I> for #quantity_type<#array_type_name, D>
+            where
+                I: ::ndarray::NdIndex<Ix>,
+                Ix: ::ndarray::Dimension,
+            {
+                type Output = #quantity_type<#float_type, D>;
+
+                fn index(&self, index: I) -> &Self::Output {
+                    let value: *const #float_type = &self.0[index];
+                    // SAFETY: Quantity is repr(transparent), so a reference to
+                    // an element can be reinterpreted as a reference to a quantity.
+                    unsafe { &*(value as *const #quantity_type<#float_type, D>) }
+                }
+            }
+
+            impl<I, Ix, const D: #dimension_type> std::ops::IndexMut<I> for #quantity_type<#array_type_name, D>
+            where
+                I: ::ndarray::NdIndex<Ix>,
+                Ix: ::ndarray::Dimension,
+            {
+                fn index_mut(&mut self, index: I) -> &mut Self::Output {
+                    let value: *mut #float_type = &mut self.0[index];
+                    // SAFETY: See `index`.
+                    unsafe { &mut *(value as *mut #quantity_type<#float_type, D>) }
+                }
+            }
+
+            impl<const D: #dimension_type> FromIterator<#quantity_type<#float_type, D>>
+                for #quantity_type<::ndarray::Array1<#float_type>, D>
+            {
+                fn from_iter<T: IntoIterator<Item = #quantity_type<#float_type, D>>>(iter: T) -> Self {
+                    Self(iter.into_iter().map(|quantity| quantity.0).collect())
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{storage_types::ComplexType, types::Defs};
+
+impl Defs {
+    pub fn complex_methods(&self) -> TokenStream {
+        self.complex_types()
+            .iter()
+            .map(|complex_type| self.impl_complex_methods(complex_type))
+            .collect()
+    }
+
+    pub fn impl_complex_methods(&self, complex_type: &ComplexType) -> TokenStream {
+        let Defs {
+            dimension_type,
+            quantity_type,
+            ..
+        } = self;
+        let ComplexType {
+            name: complex_type,
+            float_type,
+            ..
+        } = complex_type;
+        let float_type = &float_type.name;
+        quote! {
+            impl<const D: #dimension_type> #quantity_type<#complex_type, D> {
+                pub fn new(
+                    re: #quantity_type<#float_type, D>,
+                    im: #quantity_type<#float_type, D>,
+                ) -> Self {
+                    Self(<#complex_type>::new(re.value_unchecked(), im.value_unchecked()))
+                }
+
+                /// Construct a complex quantity from its norm and its argument in radians.
+                pub fn from_polar(
+                    norm: #quantity_type<#float_type, D>,
+                    arg: #quantity_type<#float_type, { #dimension_type::none() }>,
+                ) -> Self {
+                    Self(<#complex_type>::from_polar(norm.value_unchecked(), arg.value_unchecked()))
+                }
+
+                pub fn re(&self) -> #quantity_type<#float_type, D> {
+                    #quantity_type(self.0.re)
+                }
+
+                pub fn im(&self) -> #quantity_type<#float_type, D> {
+                    #quantity_type(self.0.im)
+                }
+
+                pub fn norm(&self) -> #quantity_type<#float_type, D> {
+                    #quantity_type(self.0.norm())
+                }
+
+                pub fn norm_sqr(&self) -> #quantity_type<#float_type, { D.dimension_powi(2) }>
+                where
+                    #quantity_type::<#float_type, { D.dimension_powi(2) }>:
+                {
+                    #quantity_type(self.0.norm_sqr())
+                }
+
+                /// The absolute value of the quantity, which is the same as its `norm`.
+                pub fn abs(&self) -> #quantity_type<#float_type, D> {
+                    self.norm()
+                }
+
+                /// The argument of the quantity in radians.
+                pub fn arg(&self) -> #quantity_type<#float_type, { #dimension_type::none() }> {
+                    #quantity_type(self.0.arg())
+                }
+
+                pub fn conj(&self) -> Self {
+                    Self(self.0.conj())
+                }
+
+                pub fn zero() -> Self {
+                    Self(<#complex_type>::new(0.0, 0.0))
+                }
+            }
+
+            impl #quantity_type<#complex_type, { #dimension_type::none() }> {
+                pub fn exp(&self) -> Self {
+                    Self(self.0.exp())
+                }
+
+                pub fn ln(&self) -> Self {
+                    Self(self.0.ln())
+                }
+            }
+        }
+    }
+}
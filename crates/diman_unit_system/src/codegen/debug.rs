@@ -1,23 +1,130 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
-use crate::types::Defs;
+use crate::types::{Defs, Exponent, UnitKind};
+
+use super::utils::factor_literal;
 
 impl Defs {
-    pub fn units_array(&self) -> TokenStream {
+    /// The tables of units with a symbol, which are emitted once and shared
+    /// between `Debug`, `Display`, serde and the unit parser. `UNITS` contains
+    /// the units which are related to the base units by a factor only, as
+    /// tuples of `(dimension, symbol, factor)`, `AFFINE_UNITS` the units with
+    /// an offset, as tuples of `(dimension, symbol, factor, offset)`.
+    /// Logarithmic units are not contained in either.
+    fn unit_tables(&self) -> TokenStream {
+        let Defs { dimension_type, .. } = &self;
         let units: TokenStream = self
             .units
             .iter()
+            .filter(|unit| matches!(unit.kind, UnitKind::Linear))
             .filter_map(|unit| {
                 let dim = self.get_dimension_expr(&unit.dimension);
-                let factor = unit.factor;
+                let factor = factor_literal(unit.factor);
                 let symbol = unit.symbol.as_ref()?;
                 Some(quote! {
                     (#dim, #symbol, #factor),
                 })
             })
             .collect();
-        quote! { [ #units ] }
+        let affine_units: TokenStream = self
+            .units
+            .iter()
+            .filter_map(|unit| {
+                let dim = self.get_dimension_expr(&unit.dimension);
+                let factor = factor_literal(unit.factor);
+                let offset = match unit.kind {
+                    UnitKind::Affine { offset } => offset,
+                    _ => return None,
+                };
+                let symbol = unit.symbol.as_ref()?;
+                Some(quote! {
+                    (#dim, #symbol, #factor, #offset),
+                })
+            })
+            .collect();
+        quote! {
+            pub static UNITS: &[(#dimension_type, &str, f64)] = &[ #units ];
+
+            pub static AFFINE_UNITS: &[(#dimension_type, &str, f64, f64)] = &[ #affine_units ];
+        }
+    }
+
+    pub fn closest_unit_fn(&self) -> TokenStream {
+        let Defs { dimension_type, .. } = &self;
+        quote! {
+            /// Find the unit whose magnitude is closest to the given value,
+            /// among the units whose factors are accepted by `is_allowed`.
+            pub fn closest_unit<const D: #dimension_type>(
+                value: f64,
+                is_allowed: impl Fn(f64) -> bool,
+            ) -> Option<(&'static str, f64)> {
+                let closeness = |value: f64, unit_factor: f64| {
+                    if value == 0.0 {
+                        1.0
+                    } else {
+                        (value / unit_factor).abs().ln().abs()
+                    }
+                };
+                UNITS
+                    .iter()
+                    .filter(|(d, _, factor)| d == &D && is_allowed(*factor))
+                    .min_by(|(_, _, x), (_, _, y)| {
+                        closeness(value, *x)
+                            .partial_cmp(&closeness(value, *y))
+                            .unwrap_or(std::cmp::Ordering::Equal)
+                    })
+                    .map(|(_, name, factor)| (*name, *factor))
+            }
+        }
+    }
+
+    /// Generates a function which writes a dimension as a product of powers
+    /// of base units, such as `m s^-1` or `V Hz^-1/2`. Base units are
+    /// the units with a factor of one whose dimension is a single base dimension.
+    /// Returns `None` if there is no base unit for one of the components.
+    pub fn base_unit_string_fn(&self) -> TokenStream {
+        let Defs { dimension_type, .. } = &self;
+        let mut seen_fields = vec![];
+        let components: TokenStream = self
+            .units
+            .iter()
+            .filter(|unit| matches!(unit.kind, UnitKind::Linear) && unit.factor == 1.0)
+            .filter_map(|unit| {
+                let symbol = unit.symbol.as_ref()?;
+                let [field] = &unit.dimension.fields[..] else {
+                    return None;
+                };
+                if field.value != Exponent::int(1) || seen_fields.contains(&field.ident) {
+                    return None;
+                }
+                seen_fields.push(field.ident.clone());
+                let base = self.get_dimension_expr(&unit.dimension);
+                let zero = quote! { diman::exponent::<_, 0, 1>() };
+                let without_base = self.set_dimension_field(&field.ident, zero, quote! { remaining });
+                Some(quote! {
+                    match remaining.exponent_along(&#base) {
+                        (0, _) => {}
+                        (1, 1) => components.push(#symbol.to_string()),
+                        (numerator, 1) => components.push(format!("{}^{}", #symbol, numerator)),
+                        (numerator, denominator) => {
+                            components.push(format!("{}^{}/{}", #symbol, numerator, denominator))
+                        }
+                    }
+                    remaining = #without_base;
+                })
+            })
+            .collect();
+        quote! {
+            #[allow(unused_mut, clippy::needless_update)]
+            pub fn base_unit_string(dimension: &#dimension_type) -> Option<String> {
+                let none = #dimension_type::none();
+                let mut remaining = dimension.clone();
+                let mut components: Vec<String> = vec![];
+                #components
+                (remaining == none).then(|| components.join(" "))
+            }
+        }
     }
 
     pub fn debug_trait(&self) -> TokenStream {
@@ -26,30 +133,30 @@ impl Defs {
             dimension_type,
             ..
         } = &self;
-        let units = self.units_array();
+        let unit_tables = self.unit_tables();
+        let closest_unit_fn = self.closest_unit_fn();
+        let base_unit_string_fn = self.base_unit_string_fn();
         quote! {
+            /// Helpers for writing the units of quantities, which are
+            /// shared between `Debug`, `Display` and serde.
+            #[doc(hidden)]
+            mod __diman_unit_names {
+                use super::*;
+
+                #unit_tables
+
+                #closest_unit_fn
+
+                #base_unit_string_fn
+            }
+
             impl<const D: #dimension_type, S: diman::DebugStorageType + std::fmt::Display> std::fmt::Debug for #quantity_type<S, D> {
                 fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                    let closeness = |value: f64, unit_factor: f64| {
-                        if value == 0.0 {
-                            1.0
-                        } else {
-                            (value / unit_factor).abs().ln().abs()
-                        }
-                    };
-                    let val = self.0.representative_value();
-                    let units = #units;
-                    let (unit_name, unit_value) = units
-                        .iter()
-                        .filter(|(d, _, _)| d == &D)
-                        .min_by(|(_, _, x), (_, _, y)| {
-                            closeness(val, *x)
-                                .partial_cmp(&closeness(val, *y))
-                                .unwrap_or(std::cmp::Ordering::Equal)
-                        })
-                        .map(|(_, name, val)| (name, val))
-                        .unwrap_or((&"unknown unit", &1.0));
-                    (self.0.div_f64(*unit_value))
+                    let (unit_name, unit_value) = __diman_unit_names::closest_unit::<D>(self.0.representative_value(), |factor| {
+                        self.0.is_exact_multiple(factor)
+                    })
+                        .unwrap_or(("unknown unit", 1.0));
+                    (self.0.div_f64(unit_value))
                         .fmt(f)
                         .and_then(|_| write!(f, " {}", unit_name))
                 }
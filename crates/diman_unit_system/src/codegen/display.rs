@@ -0,0 +1,98 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::types::Defs;
+
+impl Defs {
+    pub fn display_trait(&self) -> TokenStream {
+        let Defs {
+            quantity_type,
+            dimension_type,
+            ..
+        } = &self;
+        quote! {
+            /// Displays a quantity in a chosen unit. Created via
+            /// `display_as` or the `display_in_*` method of the unit.
+            pub struct QuantityDisplay<'a, S: 'static, const D: #dimension_type> {
+                quantity: &'a #quantity_type<S, D>,
+                /// The symbol of the unit, its factor and its offset.
+                unit: Option<(&'static str, f64, f64)>,
+            }
+
+            impl<const D: #dimension_type, S: diman::DebugStorageType + std::fmt::Display> std::fmt::Display for QuantityDisplay<'_, S, D> {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    use std::fmt::Write;
+                    let (unit_name, unit_value, unit_offset) = self
+                        .unit
+                        .or_else(|| {
+                            __diman_unit_names::closest_unit::<D>(self.quantity.0.representative_value(), |factor| {
+                                self.quantity.0.is_exact_multiple(factor)
+                            })
+                                .map(|(name, factor)| (name, factor, 0.0))
+                        })
+                        .map(|(name, factor, offset)| (name.to_string(), factor, offset))
+                        .unwrap_or_else(|| {
+                            let name = __diman_unit_names::base_unit_string(&D).unwrap_or_else(|| "unknown unit".to_string());
+                            (name, 1.0, 0.0)
+                        });
+                    let value = self.quantity.0.div_f64(unit_value).sub_f64(unit_offset);
+                    let mut formatted = match f.precision() {
+                        Some(precision) => format!("{:.*}", precision, value),
+                        None => format!("{}", value),
+                    };
+                    if !unit_name.is_empty() {
+                        write!(formatted, " {}", unit_name)?;
+                    }
+                    let padding = f
+                        .width()
+                        .unwrap_or(0)
+                        .saturating_sub(formatted.chars().count());
+                    let (padding_before, padding_after) = match f.align() {
+                        Some(std::fmt::Alignment::Left) => (0, padding),
+                        Some(std::fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+                        Some(std::fmt::Alignment::Right) | None => (padding, 0),
+                    };
+                    for _ in 0..padding_before {
+                        f.write_char(f.fill())?;
+                    }
+                    f.write_str(&formatted)?;
+                    for _ in 0..padding_after {
+                        f.write_char(f.fill())?;
+                    }
+                    Ok(())
+                }
+            }
+
+            impl<const D: #dimension_type, S: diman::DebugStorageType + std::fmt::Display> std::fmt::Display for #quantity_type<S, D> {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    QuantityDisplay {
+                        quantity: self,
+                        unit: None,
+                    }
+                    .fmt(f)
+                }
+            }
+
+            impl<S, const D: #dimension_type> #quantity_type<S, D> {
+                /// Display the quantity in the unit with the given symbol,
+                /// for example `length.display_as("km")`. Returns `None` if
+                /// there is no unit with this symbol and the dimension of the quantity.
+                pub fn display_as(&self, symbol: &str) -> Option<QuantityDisplay<'_, S, D>> {
+                    __diman_unit_names::UNITS
+                        .iter()
+                        .map(|(d, unit_symbol, factor)| (d, *unit_symbol, *factor, 0.0))
+                        .chain(
+                            __diman_unit_names::AFFINE_UNITS
+                                .iter()
+                                .map(|(d, unit_symbol, factor, offset)| (d, *unit_symbol, *factor, *offset)),
+                        )
+                        .find(|(d, unit_symbol, _, _)| *d == &D && *unit_symbol == symbol)
+                        .map(|(_, unit_symbol, factor, offset)| QuantityDisplay {
+                            quantity: self,
+                            unit: Some((unit_symbol, factor, offset)),
+                        })
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::types::Defs;
+
+impl Defs {
+    pub fn dyn_quantity(&self) -> TokenStream {
+        let Defs {
+            quantity_type,
+            dimension_type,
+            ..
+        } = &self;
+        quote! {
+            /// The error returned when the dimensions of two runtime
+            /// quantities (or a runtime quantity and a quantity type)
+            /// do not match.
+            #[derive(Clone, Debug, PartialEq, Eq)]
+            pub struct DimensionMismatch {
+                pub expected: #dimension_type,
+                pub found: #dimension_type,
+            }
+
+            impl std::fmt::Display for DimensionMismatch {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    write!(
+                        f,
+                        "mismatch in dimensions: expected: {:?} found: {:?}",
+                        self.expected, self.found
+                    )
+                }
+            }
+
+            impl std::error::Error for DimensionMismatch {}
+
+            /// A quantity whose dimension is only known at runtime.
+            /// Can be converted into a quantity with a compile-time
+            /// dimension via `TryFrom`.
+            #[derive(Clone, Debug, PartialEq)]
+            pub struct DynQuantity<S> {
+                value: S,
+                dimension: #dimension_type,
+            }
+
+            impl<S> DynQuantity<S> {
+                /// Create a new runtime quantity from a value and a dimension.
+                /// Use carefully, since the constructed quantity depends on the
+                /// used base units.
+                pub fn new_unchecked(value: S, dimension: #dimension_type) -> Self {
+                    Self { value, dimension }
+                }
+
+                /// Return the value of the quantity in base units.
+                pub fn value_unchecked(self) -> S {
+                    self.value
+                }
+
+                pub fn dimension(&self) -> &#dimension_type {
+                    &self.dimension
+                }
+
+                fn check_dimension(&self, expected: &#dimension_type) -> Result<(), DimensionMismatch> {
+                    if &self.dimension == expected {
+                        Ok(())
+                    } else {
+                        Err(DimensionMismatch {
+                            expected: expected.clone(),
+                            found: self.dimension.clone(),
+                        })
+                    }
+                }
+
+                /// Add two runtime quantities, failing if their dimensions differ.
+                pub fn try_add(self, rhs: Self) -> Result<Self, DimensionMismatch>
+                where
+                    S: std::ops::Add<Output = S>,
+                {
+                    rhs.check_dimension(&self.dimension)?;
+                    Ok(Self {
+                        value: self.value + rhs.value,
+                        dimension: self.dimension,
+                    })
+                }
+
+                /// Subtract two runtime quantities, failing if their dimensions differ.
+                pub fn try_sub(self, rhs: Self) -> Result<Self, DimensionMismatch>
+                where
+                    S: std::ops::Sub<Output = S>,
+                {
+                    rhs.check_dimension(&self.dimension)?;
+                    Ok(Self {
+                        value: self.value - rhs.value,
+                        dimension: self.dimension,
+                    })
+                }
+            }
+
+            impl<LHS, RHS> std::ops::Mul<DynQuantity<RHS>> for DynQuantity<LHS>
+            where
+                LHS: std::ops::Mul<RHS>,
+            {
+                type Output = DynQuantity<<LHS as std::ops::Mul<RHS>>::Output>;
+
+                fn mul(self, rhs: DynQuantity<RHS>) -> Self::Output {
+                    DynQuantity {
+                        value: self.value * rhs.value,
+                        dimension: self.dimension.dimension_mul(rhs.dimension),
+                    }
+                }
+            }
+
+            impl<LHS, RHS> std::ops::Div<DynQuantity<RHS>> for DynQuantity<LHS>
+            where
+                LHS: std::ops::Div<RHS>,
+            {
+                type Output = DynQuantity<<LHS as std::ops::Div<RHS>>::Output>;
+
+                fn div(self, rhs: DynQuantity<RHS>) -> Self::Output {
+                    DynQuantity {
+                        value: self.value / rhs.value,
+                        dimension: self.dimension.dimension_div(rhs.dimension),
+                    }
+                }
+            }
+
+            impl<S: std::ops::Neg<Output = S>> std::ops::Neg for DynQuantity<S> {
+                type Output = Self;
+
+                fn neg(self) -> Self::Output {
+                    Self {
+                        value: -self.value,
+                        dimension: self.dimension,
+                    }
+                }
+            }
+
+            impl<S, const D: #dimension_type> From<#quantity_type<S, D>> for DynQuantity<S> {
+                fn from(quantity: #quantity_type<S, D>) -> Self {
+                    Self {
+                        value: quantity.0,
+                        dimension: D,
+                    }
+                }
+            }
+
+            impl<S, const D: #dimension_type> TryFrom<DynQuantity<S>> for #quantity_type<S, D> {
+                type Error = DimensionMismatch;
+
+                fn try_from(quantity: DynQuantity<S>) -> Result<Self, Self::Error> {
+                    quantity.check_dimension(&D)?;
+                    Ok(Self(quantity.value))
+                }
+            }
+        }
+    }
+}
@@ -100,6 +100,16 @@ impl Defs {
                     #quantity_type::<#float_type, { D.dimension_powi(I) }>(self.0.powi(I))
                 }
 
+                /// Raise the quantity to the rational power `NUM / DEN`.
+                /// Requires a dimension type with rational exponents, unless
+                /// the resulting exponents are integers.
+                pub fn powr<const NUM: i32, const DEN: i32>(&self) -> #quantity_type<#float_type, { D.dimension_powr(NUM, DEN) }>
+                where
+                    #quantity_type::<#float_type, { D.dimension_powr(NUM, DEN) }>:
+                {
+                    #quantity_type::<#float_type, { D.dimension_powr(NUM, DEN) }>(self.0.powf(NUM as #float_type / DEN as #float_type))
+                }
+
                 pub fn sqrt(&self) -> #quantity_type<#float_type, { D.dimension_sqrt() }>
                 {
                     #quantity_type::<#float_type, { D.dimension_sqrt() }>(self.0.sqrt())
@@ -25,7 +25,7 @@ impl Defs {
     }
 
     pub fn generic_methods(&self) -> TokenStream {
-        self.storage_type_names()
+        self.real_storage_type_names()
             .iter()
             .map(|name| self.impl_method_for_generic_storage_type(name, &quote! { abs }))
             .collect()
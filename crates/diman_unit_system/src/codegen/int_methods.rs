@@ -0,0 +1,166 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::{storage_types::IntType, types::Defs};
+
+impl Defs {
+    pub fn int_methods(&self) -> TokenStream {
+        self.int_types()
+            .iter()
+            .map(|int_type| self.specific_int_methods(int_type))
+            .collect()
+    }
+
+    /// The checked, wrapping and saturating versions of the
+    /// arithmetic operations between two quantities.
+    fn int_arithmetic_methods(&self, int_type: &IntType) -> TokenStream {
+        let int_type = &int_type.name;
+        let Self {
+            dimension_type,
+            quantity_type,
+            ..
+        } = &self;
+        ["checked", "wrapping", "saturating"]
+            .into_iter()
+            .map(|kind| {
+                let add = format_ident!("{}_add", kind);
+                let sub = format_ident!("{}_sub", kind);
+                let mul = format_ident!("{}_mul", kind);
+                let div = format_ident!("{}_div", kind);
+                let checked = kind == "checked";
+                let output = |type_: TokenStream| {
+                    if checked {
+                        quote! { Option<#type_> }
+                    } else {
+                        type_
+                    }
+                };
+                let wrap = |constructor: TokenStream, value: TokenStream| {
+                    if checked {
+                        quote! { #value.map(#constructor) }
+                    } else {
+                        quote! { #constructor(#value) }
+                    }
+                };
+                let same = output(quote! { Self });
+                let product = output(quote! { #quantity_type<#int_type, { D.dimension_mul(DR) }> });
+                let quotient =
+                    output(quote! { #quantity_type<#int_type, { D.dimension_div(DR) }> });
+                let add_value = wrap(quote! { Self }, quote! { self.0.#add(rhs.0) });
+                let sub_value = wrap(quote! { Self }, quote! { self.0.#sub(rhs.0) });
+                let mul_value = wrap(
+                    quote! { #quantity_type::<#int_type, { D.dimension_mul(DR) }> },
+                    quote! { self.0.#mul(rhs.0) },
+                );
+                let div_value = wrap(
+                    quote! { #quantity_type::<#int_type, { D.dimension_div(DR) }> },
+                    quote! { self.0.#div(rhs.0) },
+                );
+                quote! {
+                    impl<const D: #dimension_type> #quantity_type<#int_type, D> {
+                        pub fn #add(self, rhs: Self) -> #same {
+                            #add_value
+                        }
+
+                        pub fn #sub(self, rhs: Self) -> #same {
+                            #sub_value
+                        }
+
+                        pub fn #mul<const DR: #dimension_type>(
+                            self,
+                            rhs: #quantity_type<#int_type, DR>,
+                        ) -> #product
+                        where
+                            #quantity_type<#int_type, { D.dimension_mul(DR) }>:,
+                        {
+                            #mul_value
+                        }
+
+                        pub fn #div<const DR: #dimension_type>(
+                            self,
+                            rhs: #quantity_type<#int_type, DR>,
+                        ) -> #quotient
+                        where
+                            #quantity_type<#int_type, { D.dimension_div(DR) }>:,
+                        {
+                            #div_value
+                        }
+                    }
+                }
+            })
+            .collect()
+    }
+
+    fn specific_int_methods(&self, int_type: &IntType) -> TokenStream {
+        let arithmetic_methods = self.int_arithmetic_methods(int_type);
+        let int_type = &int_type.name;
+        let Self {
+            dimension_type,
+            quantity_type,
+            ..
+        } = &self;
+        quote! {
+            #arithmetic_methods
+
+            impl<const D: #dimension_type> #quantity_type<#int_type, D> {
+                pub fn checked_neg(self) -> Option<Self> {
+                    self.0.checked_neg().map(Self)
+                }
+
+                pub fn wrapping_neg(self) -> Self {
+                    Self(self.0.wrapping_neg())
+                }
+
+                pub fn saturating_neg(self) -> Self {
+                    Self(self.0.saturating_neg())
+                }
+
+                pub fn squared(&self) -> #quantity_type<#int_type, { D.dimension_powi(2) }>
+                where
+                    #quantity_type::<#int_type, { D.dimension_powi(2) }>:
+                {
+                    #quantity_type::<#int_type, { D.dimension_powi(2) }>(self.0.pow(2))
+                }
+
+                pub fn cubed(&self) -> #quantity_type<#int_type, { D.dimension_powi(3) }>
+                where
+                    #quantity_type::<#int_type, { D.dimension_powi(3) }>:
+                {
+                    #quantity_type::<#int_type, { D.dimension_powi(3) }>(self.0.pow(3))
+                }
+
+                pub fn min(self, other: Self) -> Self {
+                    Self(self.0.min(other.0))
+                }
+
+                pub fn max(self, other: Self) -> Self {
+                    Self(self.0.max(other.0))
+                }
+
+                pub fn clamp(self, min: Self, max: Self) -> Self {
+                    Self(self.0.clamp(min.0, max.0))
+                }
+
+                pub fn zero() -> Self {
+                    Self(0)
+                }
+
+                pub fn is_positive(&self) -> bool {
+                    self.0 > 0
+                }
+
+                pub fn is_positive_or_zero(&self) -> bool {
+                    self.0 >= 0
+                }
+
+                pub fn is_negative(&self) -> bool {
+                    self.0 < 0
+                }
+
+                pub fn is_negative_or_zero(&self) -> bool {
+                    self.0 <= 0
+                }
+            }
+        }
+    }
+}
@@ -1,10 +1,17 @@
+mod array_methods;
+mod complex_methods;
 mod debug;
+mod display;
+mod dyn_quantity;
 mod float_methods;
 mod generic_methods;
 #[cfg(feature = "hdf5")]
 mod hdf5;
+mod int_methods;
 #[cfg(feature = "mpi")]
 mod mpi;
+mod nalgebra_methods;
+mod quantity_point;
 #[cfg(feature = "rand")]
 mod rand;
 #[cfg(feature = "serde")]
@@ -12,6 +19,7 @@ mod serde;
 mod traits;
 pub mod type_defs;
 mod unit_constructors;
+mod unit_parser;
 mod utils;
 mod vector_methods;
 
@@ -26,14 +34,22 @@ impl Defs {
         join([
             self.type_definition(),
             self.type_functions(),
-            self.float_definitions(),
-            self.vector_definitions(),
+            self.quantity_point(),
+            self.dimension_field_checks(),
+            self.storage_type_definitions(),
             self.unit_constructors(),
             self.qproduct_trait(),
             self.numeric_traits(),
             self.debug_trait(),
+            self.display_trait(),
+            self.dyn_quantity(),
+            self.unit_parser(),
             self.float_methods(),
+            self.int_methods(),
             self.vector_methods(),
+            self.complex_methods(),
+            self.array_methods(),
+            self.nalgebra_methods(),
             self.generic_methods(),
             #[cfg(feature = "serde")]
             self.serde_impl(),
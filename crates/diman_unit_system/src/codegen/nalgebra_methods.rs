@@ -0,0 +1,237 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    storage_types::{NalgebraKind, NalgebraType},
+    types::Defs,
+};
+
+impl Defs {
+    pub fn nalgebra_methods(&self) -> TokenStream {
+        self.nalgebra_types()
+            .iter()
+            .map(|nalgebra_type| match nalgebra_type.kind {
+                NalgebraKind::Vector => self.impl_nalgebra_vector_methods(nalgebra_type),
+                NalgebraKind::Matrix => self.impl_nalgebra_matrix_methods(nalgebra_type),
+                NalgebraKind::Rotation => self.impl_nalgebra_rotation_methods(nalgebra_type),
+            })
+            .collect()
+    }
+
+    fn impl_nalgebra_vector_methods(&self, nalgebra_type: &NalgebraType) -> TokenStream {
+        let Defs {
+            dimension_type,
+            quantity_type,
+            ..
+        } = self;
+        let vector_type_name = &nalgebra_type.name;
+        let float_type = &nalgebra_type.float_type.name;
+        quote! {
+            impl<const D: #dimension_type> #quantity_type<#vector_type_name, D> {
+                pub fn new(
+                    x: #quantity_type<#float_type, D>,
+                    y: #quantity_type<#float_type, D>,
+                    z: #quantity_type<#float_type, D>,
+                ) -> Self {
+                    Self(<#vector_type_name>::new(x.value_unchecked(), y.value_unchecked(), z.value_unchecked()))
+                }
+
+                pub fn new_x(q: #quantity_type<#float_type, D>) -> Self {
+                    Self(<#vector_type_name>::x() * q.value_unchecked())
+                }
+
+                pub fn new_y(q: #quantity_type<#float_type, D>) -> Self {
+                    Self(<#vector_type_name>::y() * q.value_unchecked())
+                }
+
+                pub fn new_z(q: #quantity_type<#float_type, D>) -> Self {
+                    Self(<#vector_type_name>::z() * q.value_unchecked())
+                }
+
+                pub fn x(&self) -> #quantity_type<#float_type, D> {
+                    #quantity_type(self.0.x)
+                }
+
+                pub fn y(&self) -> #quantity_type<#float_type, D> {
+                    #quantity_type(self.0.y)
+                }
+
+                pub fn z(&self) -> #quantity_type<#float_type, D> {
+                    #quantity_type(self.0.z)
+                }
+
+                pub fn set_x(&mut self, new_x: #quantity_type<#float_type, D>) {
+                    self.0.x = new_x.value_unchecked();
+                }
+
+                pub fn set_y(&mut self, new_y: #quantity_type<#float_type, D>) {
+                    self.0.y = new_y.value_unchecked();
+                }
+
+                pub fn set_z(&mut self, new_z: #quantity_type<#float_type, D>) {
+                    self.0.z = new_z.value_unchecked();
+                }
+
+                pub fn zero() -> Self {
+                    Self(<#vector_type_name>::zeros())
+                }
+
+                /// The componentwise minimum of the two vectors.
+                pub fn min(self, rhs: Self) -> Self {
+                    Self(self.0.inf(&rhs.0))
+                }
+
+                /// The componentwise maximum of the two vectors.
+                pub fn max(self, rhs: Self) -> Self {
+                    Self(self.0.sup(&rhs.0))
+                }
+
+                pub fn length(&self) -> #quantity_type<#float_type, D> {
+                    #quantity_type::<#float_type, D>(self.0.norm())
+                }
+
+                pub fn distance(&self, other: &Self) -> #quantity_type<#float_type, D> {
+                    #quantity_type::<#float_type, D>((self.0 - other.0).norm())
+                }
+
+                pub fn distance_squared(
+                    &self,
+                    other: &Self,
+                ) -> #quantity_type<#float_type, { D.dimension_powi(2) }>
+                where
+                    #quantity_type<#float_type, { D.dimension_powi(2) }>:,
+                {
+                    #quantity_type::<#float_type, { D.dimension_powi(2) }>((self.0 - other.0).norm_squared())
+                }
+
+                pub fn normalize(&self) -> #quantity_type<#vector_type_name, { #dimension_type::none() }> {
+                    #quantity_type::<#vector_type_name, { #dimension_type::none() }>(self.0.normalize())
+                }
+
+                pub fn dot<const DR: #dimension_type>(
+                    self,
+                    rhs: #quantity_type<#vector_type_name, DR>,
+                ) -> #quantity_type<#float_type, { D.dimension_mul(DR) }> {
+                    #quantity_type(self.0.dot(&rhs.0))
+                }
+
+                pub fn cross<const DR: #dimension_type>(
+                    self,
+                    rhs: #quantity_type<#vector_type_name, DR>,
+                ) -> #quantity_type<#vector_type_name, { D.dimension_mul(DR) }> {
+                    #quantity_type(self.0.cross(&rhs.0))
+                }
+            }
+        }
+    }
+
+    /// The product of a matrix and a vector quantity is
+    /// provided by the generic `Mul` impl between quantities.
+    fn impl_nalgebra_matrix_methods(&self, nalgebra_type: &NalgebraType) -> TokenStream {
+        let Defs {
+            dimension_type,
+            quantity_type,
+            ..
+        } = self;
+        let matrix_type_name = &nalgebra_type.name;
+        let float_type = &nalgebra_type.float_type.name;
+        let vector_type_name = quote! { ::nalgebra::Vector3<#float_type> };
+        quote! {
+            impl<const D: #dimension_type> #quantity_type<#matrix_type_name, D> {
+                pub fn from_diagonal(diagonal: #quantity_type<#vector_type_name, D>) -> Self {
+                    Self(<#matrix_type_name>::from_diagonal(&diagonal.0))
+                }
+
+                pub fn zero() -> Self {
+                    Self(<#matrix_type_name>::zeros())
+                }
+
+                pub fn get(&self, row: usize, column: usize) -> Option<#quantity_type<#float_type, D>> {
+                    self.0.get((row, column)).map(|value| #quantity_type(*value))
+                }
+
+                pub fn column(&self, column: usize) -> #quantity_type<#vector_type_name, D> {
+                    #quantity_type(self.0.column(column).into_owned())
+                }
+
+                pub fn diagonal(&self) -> #quantity_type<#vector_type_name, D> {
+                    #quantity_type(self.0.diagonal())
+                }
+
+                pub fn transpose(&self) -> Self {
+                    Self(self.0.transpose())
+                }
+
+                pub fn trace(&self) -> #quantity_type<#float_type, D> {
+                    #quantity_type(self.0.trace())
+                }
+
+                pub fn determinant(&self) -> #quantity_type<#float_type, { D.dimension_powi(3) }>
+                where
+                    #quantity_type<#float_type, { D.dimension_powi(3) }>:,
+                {
+                    #quantity_type(self.0.determinant())
+                }
+
+                /// The inverse of the matrix, or `None` if it is not invertible.
+                pub fn try_inverse(&self) -> Option<#quantity_type<#matrix_type_name, { D.dimension_inv() }>>
+                where
+                    #quantity_type<#matrix_type_name, { D.dimension_inv() }>:,
+                {
+                    self.0.try_inverse().map(#quantity_type)
+                }
+            }
+
+            impl #quantity_type<#matrix_type_name, { #dimension_type::none() }> {
+                pub fn identity() -> Self {
+                    Self(<#matrix_type_name>::identity())
+                }
+            }
+        }
+    }
+
+    /// Rotating a vector quantity is also possible by multiplying it
+    /// with the rotation, via the generic `Mul` impl between quantities.
+    fn impl_nalgebra_rotation_methods(&self, nalgebra_type: &NalgebraType) -> TokenStream {
+        let Defs {
+            dimension_type,
+            quantity_type,
+            ..
+        } = self;
+        let rotation_type_name = &nalgebra_type.name;
+        let float_type = &nalgebra_type.float_type.name;
+        let vector_type_name = quote! { ::nalgebra::Vector3<#float_type> };
+        let angle_type = quote! { #quantity_type<#float_type, { #dimension_type::none() }> };
+        quote! {
+            impl #quantity_type<#rotation_type_name, { #dimension_type::none() }> {
+                pub fn identity() -> Self {
+                    Self(<#rotation_type_name>::identity())
+                }
+
+                pub fn from_axis_angle(axis: &::nalgebra::Unit<#vector_type_name>, angle: #angle_type) -> Self {
+                    Self(<#rotation_type_name>::from_axis_angle(axis, angle.value()))
+                }
+
+                pub fn from_euler_angles(roll: #angle_type, pitch: #angle_type, yaw: #angle_type) -> Self {
+                    Self(<#rotation_type_name>::from_euler_angles(roll.value(), pitch.value(), yaw.value()))
+                }
+
+                /// The rotation angle in radians.
+                pub fn angle(&self) -> #angle_type {
+                    #quantity_type(self.0.angle())
+                }
+
+                pub fn inverse(&self) -> Self {
+                    Self(self.0.inverse())
+                }
+
+                pub fn rotate<const D: #dimension_type>(
+                    &self,
+                    vector: #quantity_type<#vector_type_name, D>,
+                ) -> #quantity_type<#vector_type_name, D> {
+                    #quantity_type(self.0.transform_vector(&vector.0))
+                }
+            }
+        }
+    }
+}
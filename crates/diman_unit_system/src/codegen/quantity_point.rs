@@ -0,0 +1,93 @@
+use proc_macro2::TokenStream;
+use quote::quote_spanned;
+use syn::spanned::Spanned;
+
+use crate::types::Defs;
+
+impl Defs {
+    /// Values given in a unit with an offset, such as degrees Celsius,
+    /// are points on a scale rather than amounts, so they are stored in
+    /// a separate type. Subtracting two points gives a quantity, which is
+    /// converted into such a unit without applying the offset.
+    pub(crate) fn quantity_point(&self) -> TokenStream {
+        let Self {
+            quantity_type,
+            dimension_type,
+            ..
+        } = &self;
+        let span = quantity_type.span();
+        quote_spanned! {span =>
+            /// A point on the scale of a unit with an offset, such as a
+            /// temperature constructed via `Temperature::celsius`. The
+            /// difference of two points is a quantity.
+            #[derive(Clone, Copy, PartialEq, Eq, PartialOrd)]
+            #[repr(transparent)]
+            pub struct QuantityPoint<S: 'static, const D: #dimension_type>(pub(crate) #quantity_type<S, D>);
+
+            impl<S, const D: #dimension_type> QuantityPoint<S, D> {
+                /// Create a point from the quantity by which it is
+                /// separated from the zero point of the base units.
+                pub const fn from_absolute(quantity: #quantity_type<S, D>) -> Self {
+                    Self(quantity)
+                }
+
+                /// Return the quantity by which the point is separated
+                /// from the zero point of the base units.
+                pub fn absolute(self) -> #quantity_type<S, D> {
+                    self.0
+                }
+            }
+
+            impl<S, const D: #dimension_type> std::ops::Sub for QuantityPoint<S, D>
+            where
+                S: std::ops::Sub<Output = S>,
+            {
+                type Output = #quantity_type<S, D>;
+
+                fn sub(self, rhs: Self) -> Self::Output {
+                    #quantity_type(self.0 .0 - rhs.0 .0)
+                }
+            }
+
+            impl<S, const D: #dimension_type> std::ops::Add<#quantity_type<S, D>> for QuantityPoint<S, D>
+            where
+                S: std::ops::Add<Output = S>,
+            {
+                type Output = Self;
+
+                fn add(self, rhs: #quantity_type<S, D>) -> Self::Output {
+                    Self(#quantity_type(self.0 .0 + rhs.0))
+                }
+            }
+
+            impl<S, const D: #dimension_type> std::ops::Sub<#quantity_type<S, D>> for QuantityPoint<S, D>
+            where
+                S: std::ops::Sub<Output = S>,
+            {
+                type Output = Self;
+
+                fn sub(self, rhs: #quantity_type<S, D>) -> Self::Output {
+                    Self(#quantity_type(self.0 .0 - rhs.0))
+                }
+            }
+
+            impl<S, const D: #dimension_type> std::fmt::Debug for QuantityPoint<S, D>
+            where
+                #quantity_type<S, D>: std::fmt::Debug,
+            {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    self.0.fmt(f)
+                }
+            }
+
+            impl<S, const D: #dimension_type> std::fmt::Display for QuantityPoint<S, D>
+            where
+                #quantity_type<S, D>: std::fmt::Display,
+            {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    self.0.fmt(f)
+                }
+            }
+        }
+    }
+}
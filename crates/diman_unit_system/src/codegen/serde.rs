@@ -2,7 +2,7 @@ use proc_macro2::TokenStream;
 use quote::quote;
 
 use crate::{
-    storage_types::{FloatType, VectorType},
+    storage_types::{FloatType, IntType, VectorType},
     types::Defs,
 };
 
@@ -13,6 +13,7 @@ impl Defs {
         join([
             self.serde_helpers_impl(),
             self.serde_floats_impl(),
+            self.serde_ints_impl(),
             self.serde_vectors_impl(),
         ])
     }
@@ -24,11 +25,8 @@ impl Defs {
             ..
         } = self;
 
-        let units = self.units_array();
-
         quote! {
             use std::marker::PhantomData;
-            use std::str::SplitWhitespace;
 
             use serde::de::{self};
 
@@ -40,54 +38,9 @@ impl Defs {
                 numerical_value: S,
                 dimension: #dimension_type,
             ) -> Result<#quantity_type<S, D>, E> {
-                if dimension == D {
-                    Ok(#quantity_type::<S, D>(numerical_value))
-                } else {
-                    Err(E::custom(format!(
-                        "mismatch in dimensions: needed: {:?} given: {:?} in string: {}",
-                        D, dimension, context
-                    )))
-                }
-            }
-
-            fn read_unit_str<E: de::Error>(split: SplitWhitespace) -> Result<(#dimension_type, f64), E> {
-                let mut total_dimension = #dimension_type::none();
-                let mut total_factor = 1.0;
-                for unit in split {
-                    let (dimension, factor) = read_single_unit_str(unit)?;
-                    total_dimension = total_dimension.dimension_mul(dimension.clone());
-                    total_factor *= factor;
-                }
-                Ok((total_dimension, total_factor))
-            }
-
-            fn read_single_unit_str<E>(unit_str: &str) -> Result<(#dimension_type, f64), E>
-            where
-                E: de::Error,
-            {
-                let (unit, exponent) = if unit_str.contains('^') {
-                    let split: Vec<_> = unit_str.split('^').collect();
-                    if split.len() != 2 {
-                        return Err(E::custom(format!("invalid unit string: {}", unit_str)));
-                    }
-                    (
-                        split[0],
-                        split[1].parse::<i32>().map_err(|_| {
-                            E::custom(format!("unable to parse unit exponent: {}", split[1]))
-                        })?,
-                    )
-                } else {
-                    (unit_str, 1)
-                };
-                let units = #units;
-                let (dimension, _, factor) = units
-                    .iter()
-                    .find(|(_, known_unit_name, _)| &unit == known_unit_name)
-                    .ok_or_else(|| E::custom(format!("unknown unit: {}", &unit)))?;
-                Ok((
-                    dimension.clone().dimension_powi(exponent),
-                    factor.powi(exponent),
-                ))
+                DynQuantity::new_unchecked(numerical_value, dimension)
+                    .try_into()
+                    .map_err(|e| E::custom(format!("{} in string: {}", e, context)))
             }
         }
     }
@@ -105,7 +58,6 @@ impl Defs {
             quantity_type,
             ..
         } = self;
-        let units = self.units_array();
         let serialize_method = &float_type.serialize_method;
         let float_type = &float_type.name;
         quote! {
@@ -170,23 +122,7 @@ impl Defs {
                 where
                     E: de::Error,
                 {
-                    let value = value.trim();
-                    let mut split = value.split_whitespace();
-                    let numerical_value_str = split
-                        .next()
-                        .ok_or_else(|| E::custom("unable to parse empty string"))?;
-                    let numerical_value = numerical_value_str.parse::<#float_type>().map_err(|_| {
-                        E::custom(format!(
-                            "unable to parse numerical value {}",
-                            &numerical_value_str
-                        ))
-                    })?;
-                    let (total_dimension, total_factor) = read_unit_str(split)?;
-                    get_quantity_if_dimensions_match::<#float_type, D, E>(
-                        value,
-                        (numerical_value * (total_factor as #float_type)),
-                        total_dimension,
-                    )
+                    value.parse::<#quantity_type<#float_type, D>>().map_err(E::custom)
                 }
             }
 
@@ -195,18 +131,18 @@ impl Defs {
                 where
                     S: serde::Serializer,
                 {
-                    let units = #units;
                     if D == #dimension_type::none() {
                         serializer.#serialize_method(self.0)
                     } else {
-                        let unit_name = units
+                        let unit_name = __diman_unit_names::UNITS
                             .iter()
                             .filter(|(d, _, _)| d == &D)
                             .filter(|(_, _, val)| *val == 1.0)
-                            .map(|(_, name, _)| name)
+                            .map(|(_, name, _)| name.to_string())
                             .next()
+                            .or_else(|| __diman_unit_names::base_unit_string(&D))
                             .unwrap_or_else(|| {
-                                panic!("Attempt to deserialize quantity with unnamed unit.")
+                                panic!("Attempt to serialize quantity with unnamed unit.")
                             });
                         serializer.serialize_str(&format!("{} {}", self.0.to_string(), unit_name))
                     }
@@ -215,6 +151,105 @@ impl Defs {
         }
     }
 
+    pub fn serde_ints_impl(&self) -> TokenStream {
+        self.int_types()
+            .iter()
+            .map(|int_type| self.serde_int_impl(int_type))
+            .collect()
+    }
+
+    /// Quantities with integer storage are serialized like those with float
+    /// storage, but can only be deserialized from integer values.
+    pub fn serde_int_impl(&self, int_type: &IntType) -> TokenStream {
+        let Defs {
+            dimension_type,
+            quantity_type,
+            ..
+        } = self;
+        let serialize_method = &int_type.serialize_method;
+        let int_type = &int_type.name;
+        quote! {
+            impl<'de, const D: #dimension_type> serde::Deserialize<'de> for #quantity_type<#int_type, D> {
+                fn deserialize<DE>(deserializer: DE) -> Result<#quantity_type<#int_type, D>, DE::Error>
+                where
+                    DE: serde::Deserializer<'de>,
+                {
+                    deserializer.deserialize_string(QuantityVisitor::<#int_type, D>::default())
+                }
+            }
+
+            impl<'de, const D: #dimension_type> serde::de::Visitor<'de> for QuantityVisitor<#int_type, D> {
+                type Value = #quantity_type<#int_type, D>;
+
+                fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                    formatter.write_str("an integer value followed by a series of powers of units")
+                }
+
+                fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    if D == #dimension_type::none() {
+                        #int_type::try_from(value)
+                            .map(#quantity_type::<#int_type, D>)
+                            .map_err(E::custom)
+                    } else {
+                        Err(E::custom(format!(
+                            "dimensionless numerical value given for non-dimensionless quantity: {}",
+                            value
+                        )))
+                    }
+                }
+
+                fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    if D == #dimension_type::none() {
+                        #int_type::try_from(value)
+                            .map(#quantity_type::<#int_type, D>)
+                            .map_err(E::custom)
+                    } else {
+                        Err(E::custom(format!(
+                            "dimensionless numerical value given for non-dimensionless quantity: {}",
+                            value
+                        )))
+                    }
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                where
+                    E: de::Error,
+                {
+                    value.parse::<#quantity_type<#int_type, D>>().map_err(E::custom)
+                }
+            }
+
+            impl<const D: #dimension_type> serde::Serialize for #quantity_type<#int_type, D> {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer,
+                {
+                    if D == #dimension_type::none() {
+                        serializer.#serialize_method(self.0)
+                    } else {
+                        let unit_name = __diman_unit_names::UNITS
+                            .iter()
+                            .filter(|(d, _, _)| d == &D)
+                            .filter(|(_, _, val)| *val == 1.0)
+                            .map(|(_, name, _)| name.to_string())
+                            .next()
+                            .or_else(|| __diman_unit_names::base_unit_string(&D))
+                            .unwrap_or_else(|| {
+                                panic!("Attempt to serialize quantity with unnamed unit.")
+                            });
+                        serializer.serialize_str(&format!("{} {}", self.0, unit_name))
+                    }
+                }
+            }
+        }
+    }
+
     pub fn serde_vectors_impl(&self) -> TokenStream {
         self.vector_types()
             .iter()
@@ -231,7 +266,6 @@ impl Defs {
             quantity_type,
             ..
         } = self;
-        let units = self.units_array();
         quote! {
             impl<'de, const D: #dimension_type> serde::Deserialize<'de> for #quantity_type<#vector_type, D> {
                 fn deserialize<DE>(deserializer: DE) -> Result<#quantity_type<#vector_type, D>, DE::Error>
@@ -280,7 +314,8 @@ impl Defs {
 
                     }
                     let vector = <#vector_type>::from_array(array);
-                    let (total_dimension, total_factor) = read_unit_str(unit_part.split_whitespace())?;
+                    let (total_dimension, total_factor) =
+                        parse_unit_expression(unit_part).map_err(E::custom)?;
                     get_quantity_if_dimensions_match::<#vector_type, D, E>(
                         value,
                         (total_factor as #float_type) * vector,
@@ -300,15 +335,15 @@ impl Defs {
                     if D == #dimension_type::none() {
                         serializer.serialize_str(&vec_to_string(self.0))
                     } else {
-                        let units = #units;
-                        let unit_name = units
+                            let unit_name = __diman_unit_names::UNITS
                             .iter()
                             .filter(|(d, _, _)| d == &D)
                             .filter(|(_, _, val)| *val == 1.0)
-                            .map(|(_, name, _)| name)
+                            .map(|(_, name, _)| name.to_string())
                             .next()
+                            .or_else(|| __diman_unit_names::base_unit_string(&D))
                             .unwrap_or_else(|| {
-                                panic!("Attempt to deserialize quantity with unnamed unit.")
+                                panic!("Attempt to serialize quantity with unnamed unit.")
                             });
                         serializer.serialize_str(&format!("{} {}", vec_to_string(self.0), unit_name))
                     }
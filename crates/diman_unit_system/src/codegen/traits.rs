@@ -438,7 +438,9 @@ impl NumericTrait {
             fn_name: fn_name.clone(),
             fn_return_type,
             fn_args: quote! { &self, other: &#rhs },
-            fn_return_expr: quote! { self.#fn_name(&other.0) },
+            // Fully qualified, since storage types such as the `nalgebra`
+            // matrices have inherent methods of the same name.
+            fn_return_expr: quote! { #name::#fn_name(self, &other.0) },
             trait_bound_impl: quote! { #lhs: #name<RHS> },
             output_type_def: quote! {},
             impl_generics: quote! { < RHS > },
@@ -545,6 +547,17 @@ impl Defs {
             self.storage_type_names()
                 .into_iter()
                 .flat_map(move |storage_type| {
+                    // ndarray only implements AddAssign and SubAssign
+                    // between arrays for a borrowed right hand side.
+                    let is_array = self
+                        .array_types()
+                        .iter()
+                        .any(|array_type| array_type.concrete_types.contains(&storage_type));
+                    let rhs_value = if is_array {
+                        quote! { &rhs.0 }
+                    } else {
+                        quote! { rhs.0 }
+                    };
                     [
                         NumericTrait::mul_or_div_quantity_type(
                             self,
@@ -620,14 +633,14 @@ impl Defs {
                             self,
                             quote! { std::ops::AddAssign },
                             quote! { add_assign },
-                            quote! { *self += rhs.0; },
+                            quote! { *self += #rhs_value; },
                             &storage_type,
                         ),
                         NumericTrait::add_or_sub_assign_type_quantity(
                             self,
                             quote! { std::ops::SubAssign },
                             quote! { sub_assign },
-                            quote! { *self -= rhs.0; },
+                            quote! { *self -= #rhs_value; },
                             &storage_type,
                         ),
                         NumericTrait::cmp_trait_quantity_type(
@@ -1,10 +1,12 @@
 use crate::{
     storage_types::StorageType,
-    types::{Defs, Dimensions},
+    types::{Defs, Dimensions, Exponent},
 };
 use proc_macro2::TokenStream;
 use quote::{quote, quote_spanned};
-use syn::spanned::Spanned;
+use syn::{spanned::Spanned, Ident};
+
+use super::utils::factor_literal;
 
 impl Defs {
     pub(crate) fn type_definition(&self) -> TokenStream {
@@ -66,60 +68,152 @@ impl Defs {
         }
     }
 
-    pub fn get_dimension_expr(&self, dim: &Dimensions) -> TokenStream {
-        let dimension_type = &self.dimension_type;
-        let field_updates: TokenStream = dim
-            .fields
+    /// Checks the definitions against the dimension type, at the spans
+    /// at which they are given, since the macro itself does not know the
+    /// fields of the dimension type or their types. Every field which is
+    /// named in the definitions is set, so that the compiler reports any
+    /// field which does not exist on the dimension type. Every exponent
+    /// is passed as a literal of the type of its field, so that the compiler
+    /// reports exponents which do not fit into the field, as well as
+    /// fractional exponents of fields which are not of type `diman::Ratio`.
+    pub(crate) fn dimension_field_checks(&self) -> TokenStream {
+        let mut named: Vec<&Ident> = vec![];
+        let fields: TokenStream = self
+            .dimension_fields
             .iter()
+            .filter(|field| {
+                let is_new = !named.contains(field);
+                named.push(field);
+                is_new
+            })
             .map(|field| {
-                let ident = &field.ident;
-                let value = &field.value;
-                quote! { #ident: #value, }
+                let value = quote! { diman::exponent::<_, 0, 1>() };
+                let dimension = self.set_dimension_field(field, value, self.none_expr());
+                quote! { let _ = #dimension; }
             })
             .collect();
-        let span = self.quantity_type.span();
-        quote_spanned! {span =>
-                #dimension_type {
-                    #field_updates
-                    ..#dimension_type::none()
+        let mut checked: Vec<(&Ident, Exponent)> = vec![];
+        let exponents: TokenStream = self
+            .quantities
+            .iter()
+            .map(|quantity| (&quantity.name, &quantity.dimension))
+            .chain(self.units.iter().map(|unit| (&unit.name, &unit.dimension)))
+            .chain(
+                self.constants
+                    .iter()
+                    .map(|constant| (&constant.name, &constant.dimension)),
+            )
+            .flat_map(|(name, dimension)| dimension.fields.iter().map(move |field| (name, field)))
+            .filter(|(_, field)| {
+                let is_new = !checked.contains(&(&field.ident, field.value));
+                checked.push((&field.ident, field.value));
+                is_new
+            })
+            .map(|(name, field)| {
+                // Spanned at the definition, so that the compiler does not
+                // consider the checks to be part of the generated code.
+                let span = name.span();
+                let value = if field.value.is_int() {
+                    let mut value = proc_macro2::Literal::i64_unsuffixed(field.value.numerator());
+                    value.set_span(span);
+                    quote_spanned! {span => check_int_exponent(#value) }
+                } else {
+                    quote_spanned! {span => check_fractional_exponent() }
+                };
+                let dimension = self.set_dimension_field(&field.ident, value, self.none_expr());
+                quote_spanned! {span => let _ = #dimension; }
+            })
+            .collect();
+        quote! {
+            const _: () = {
+                #[allow(dead_code, clippy::needless_update)]
+                #[deny(overflowing_literals)]
+                fn check_dimension_fields() {
+                    fn check_int_exponent<T: diman::Exponent>(_: T::Int) -> T {
+                        unreachable!()
+                    }
+                    fn check_fractional_exponent<T: diman::FractionalExponent>() -> T {
+                        unreachable!()
+                    }
+                    #fields
+                    #exponents
                 }
+            };
         }
     }
 
-    pub fn vector_definitions(&self) -> TokenStream {
-        self.vector_types()
+    fn none_expr(&self) -> TokenStream {
+        let dimension_type = &self.dimension_type;
+        quote! { #dimension_type::none() }
+    }
+
+    /// The dimension `base` with the field of the given name set to
+    /// `value`. The field is named at the span at which it is first
+    /// named in the definitions, and every struct expression sets a single
+    /// field, so that all of the struct expressions which set a field
+    /// that does not exist on the dimension type lead to the same error,
+    /// which the compiler reports only once.
+    pub fn set_dimension_field(
+        &self,
+        field: &Ident,
+        value: TokenStream,
+        base: TokenStream,
+    ) -> TokenStream {
+        let dimension_type = &self.dimension_type;
+        let field = self
+            .dimension_fields
             .iter()
-            .map(|vector_type| {
-                self.definitions_for_storage_type(vector_type, &vector_type.module_name, false)
-            })
-            .collect()
+            .find(|first| *first == field)
+            .unwrap_or(field);
+        let span = self.quantity_type.span();
+        quote_spanned! {span =>
+            #dimension_type {
+                #field: #value,
+                ..#base
+            }
+        }
     }
 
-    pub fn float_definitions(&self) -> TokenStream {
-        self.float_types()
+    pub fn get_dimension_expr(&self, dim: &Dimensions) -> TokenStream {
+        let dimension_type = &self.dimension_type;
+        let dimension = dim.fields.iter().fold(self.none_expr(), |base, field| {
+            let numerator = field.value.numerator();
+            let denominator = field.value.denominator();
+            // The type of the exponent is inferred from the field.
+            let value = quote! { diman::exponent::<_, { #numerator }, { #denominator }>() };
+            self.set_dimension_field(&field.ident, value, base)
+        });
+        let span = self.quantity_type.span();
+        // Within a constant, since clippy reports the update as needless
+        // for dimension types with a single field.
+        quote_spanned! {span =>
+            {
+                #[allow(clippy::needless_update)]
+                const DIMENSION: #dimension_type = #dimension;
+                DIMENSION
+            }
+        }
+    }
+
+    pub fn storage_type_definitions(&self) -> TokenStream {
+        self.all_storage_types()
             .iter()
-            .map(|float_type| {
-                self.definitions_for_storage_type(float_type, &float_type.module_name, true)
-            })
+            .map(|type_| self.definitions_for_storage_type(type_.as_ref()))
             .collect()
     }
 
-    pub fn definitions_for_storage_type<T: StorageType>(
-        &self,
-        type_: &T,
-        module_name: &TokenStream,
-        gen_constants: bool,
-    ) -> TokenStream {
+    pub fn definitions_for_storage_type(&self, type_: &dyn StorageType) -> TokenStream {
         let Self {
             dimension_type,
             quantity_type,
             ..
         } = &self;
+        let module_name = type_.module_name();
         // TODO: The use statements here are quite hacky and will probably
         // not work if dimension is declared in a different place from
         // the macro invocation.
         let quantities = self.quantity_definitions_for_storage_type(type_);
-        let constants = if gen_constants {
+        let constants = if type_.has_constants() {
             self.constant_definitions_for_storage_type(type_)
         } else {
             quote! {}
@@ -134,23 +228,25 @@ impl Defs {
         }
     }
 
-    pub fn quantity_definitions_for_storage_type<T: StorageType>(&self, type_: &T) -> TokenStream {
+    pub fn quantity_definitions_for_storage_type(&self, type_: &dyn StorageType) -> TokenStream {
         self.quantities
             .iter()
+            .filter(|quantity| !type_.is_dimensionless_only() || quantity.dimension.is_none())
             .map(|quantity| {
                 let dimension = self.get_dimension_expr(&quantity.dimension);
                 let quantity_type = &self.quantity_type;
                 let quantity_name = &quantity.name;
+                let alias_generics = type_.alias_generics();
                 let type_ = type_.name();
                 let span = self.dimension_type.span();
                 quote_spanned! {span =>
-                    pub type #quantity_name = #quantity_type::<#type_, { #dimension }>;
+                    pub type #quantity_name #alias_generics = #quantity_type::<#type_, { #dimension }>;
                 }
             })
             .collect()
     }
 
-    pub fn constant_definitions_for_storage_type<T: StorageType>(&self, type_: &T) -> TokenStream {
+    pub fn constant_definitions_for_storage_type(&self, type_: &dyn StorageType) -> TokenStream {
         self
             .constants
             .iter()
@@ -158,7 +254,7 @@ impl Defs {
                 let dimension = self.get_dimension_expr(&constant.dimension);
                 let quantity_type = &self.quantity_type;
                 let constant_name = &constant.name;
-                let value = constant.factor;
+                let value = factor_literal(constant.factor);
                 let float_type = type_.base_storage();
                 let type_ = type_.name();
                 quote! {
@@ -3,97 +3,242 @@ use quote::{format_ident, quote, quote_spanned};
 use syn::spanned::Spanned;
 
 use crate::{
-    storage_types::{FloatType, VectorType},
-    types::{Defs, Unit},
+    storage_types::{Constructor, FloatType, StorageType},
+    types::{Defs, Unit, UnitKind},
 };
 
+use super::utils::factor_literal;
+
 impl Defs {
     pub fn unit_constructors(&self) -> TokenStream {
-        self.units.iter().map(|unit| {
-            let dimension = self.get_dimension_expr(&unit.dimension);
-            let quantity_type = &self.quantity_type;
-            let unit_name = &unit.name;
-            let factor = &unit.factor;
-            let conversion_method_name = format_ident!("in_{}", unit_name);
-            let vector_impls: TokenStream = self
-                .vector_types()
-                .iter()
-                .map(|vector_type| self.vector_unit_constructor(vector_type, unit, &dimension))
-                .collect();
-            let float_impls: TokenStream = self
-                .float_types()
-                .iter()
-                .map(|float_type| self.float_unit_constructor(float_type, unit, &dimension))
-                .collect();
-            quote! {
-                impl<S> #quantity_type<S, {#dimension}> where S: std::ops::Div<f64, Output = S> {
-                    pub fn #conversion_method_name(self) -> S {
-                        self.0 / #factor
+        self.units
+            .iter()
+            .map(|unit| {
+                let dimension = self.get_dimension_expr(&unit.dimension);
+                let quantity_type = &self.quantity_type;
+                let display_method = self.display_in_unit_method(unit, &dimension);
+                let display_impl = match unit.kind {
+                    UnitKind::Linear => quote! {
+                        impl<S: diman::StorageType> #quantity_type<S, {#dimension}> {
+                            #display_method
+                        }
+                    },
+                    UnitKind::Affine { .. } => quote! {
+                        impl<S: diman::StorageType> QuantityPoint<S, {#dimension}> {
+                            #display_method
+                        }
+                    },
+                    // Logarithmic units are only defined for float types,
+                    // see `logarithmic_unit_constructor`.
+                    UnitKind::Logarithmic { .. } => quote! {},
+                };
+                let conversions = self.unit_conversions(unit, &dimension);
+                let storage_type_impls: TokenStream = self
+                    .all_storage_types()
+                    .iter()
+                    .map(|storage_type| {
+                        self.storage_type_unit_constructor(storage_type.as_ref(), unit, &dimension)
+                    })
+                    .collect();
+                let logarithmic_impls: TokenStream = self
+                    .float_types()
+                    .iter()
+                    .map(|float_type| {
+                        self.logarithmic_unit_constructor(float_type, unit, &dimension)
+                    })
+                    .collect();
+                quote! {
+                    #display_impl
+                    #conversions
+                    #storage_type_impls
+                    #logarithmic_impls
+                }
+            })
+            .collect()
+    }
+
+    /// Displays the quantity with the symbol of this unit. Units
+    /// without a symbol are written with their name instead. For
+    /// units with an offset, this is a method of `QuantityPoint`.
+    fn display_in_unit_method(&self, unit: &Unit, dimension: &TokenStream) -> TokenStream {
+        let method_name = format_ident!("display_in_{}", unit.name);
+        let factor = factor_literal(unit.factor);
+        let (offset, quantity) = match unit.kind {
+            UnitKind::Affine { offset } => (offset, quote! { &self.0 }),
+            _ => (0.0, quote! { self }),
+        };
+        let symbol = unit.symbol.clone().unwrap_or_else(|| unit.name.to_string());
+        let doc = format!(
+            "Display the quantity in {}, for example via `format!(\"{{:.2}}\", quantity.{}())`.",
+            unit.name, method_name
+        );
+        quote! {
+            #[doc = #doc]
+            pub fn #method_name(&self) -> QuantityDisplay<'_, S, {#dimension}> where S: 'static {
+                QuantityDisplay {
+                    quantity: #quantity,
+                    unit: Some((#symbol, #factor, #offset)),
+                }
+            }
+        }
+    }
+
+    /// The conversions into a unit are generated for each storage type
+    /// which implements `diman::StorageType`. Since the integer types
+    /// round the result, they only get the conversions into units for
+    /// which the result is exact.
+    fn unit_conversions(&self, unit: &Unit, quantity_dimension: &TokenStream) -> TokenStream {
+        self.all_storage_types()
+            .iter()
+            .filter(|storage_type| storage_type.has_conversion(unit))
+            .map(|storage_type| {
+                let name = storage_type.name();
+                self.storage_type_unit_conversion(
+                    unit,
+                    quantity_dimension,
+                    storage_type.impl_generics(),
+                    quote! { #name },
+                )
+            })
+            .collect()
+    }
+
+    fn storage_type_unit_conversion(
+        &self,
+        unit: &Unit,
+        quantity_dimension: &TokenStream,
+        impl_generics: TokenStream,
+        storage_type: TokenStream,
+    ) -> TokenStream {
+        let quantity_type = &self.quantity_type;
+        let factor = factor_literal(unit.factor);
+        let conversion_method_name = format_ident!("in_{}", unit.name);
+        match unit.kind {
+            UnitKind::Linear => quote! {
+                impl #impl_generics #quantity_type<#storage_type, {#quantity_dimension}> {
+                    pub fn #conversion_method_name(self) -> #storage_type {
+                        diman::StorageType::div_f64(&self.0, #factor)
+                    }
+                }
+            },
+            UnitKind::Affine { offset } => {
+                let difference_method_name = format_ident!("in_{}_difference", unit.name);
+                quote! {
+                    impl #impl_generics QuantityPoint<#storage_type, {#quantity_dimension}> {
+                        pub fn #conversion_method_name(self) -> #storage_type {
+                            diman::StorageType::sub_f64(
+                                &diman::StorageType::div_f64(&self.0 .0, #factor),
+                                #offset,
+                            )
+                        }
+                    }
+
+                    impl #impl_generics #quantity_type<#storage_type, {#quantity_dimension}> {
+                        /// Convert a difference of two points into this unit,
+                        /// to which the offset of the unit does not apply.
+                        pub fn #difference_method_name(self) -> #storage_type {
+                            diman::StorageType::div_f64(&self.0, #factor)
+                        }
                     }
                 }
-                #float_impls
-                #vector_impls
             }
-        }).collect()
+            UnitKind::Logarithmic { .. } => quote! {},
+        }
     }
 
-    fn float_unit_constructor(
+    /// The constructors of a unit for one storage type. Units with an
+    /// offset construct a `QuantityPoint`, while `<unit>_difference`
+    /// constructs the difference of two such points, to which the offset
+    /// does not apply.
+    fn storage_type_unit_constructor(
         &self,
-        float_type: &FloatType,
+        storage_type: &dyn StorageType,
         unit: &Unit,
         quantity_dimension: &TokenStream,
     ) -> TokenStream {
         let Defs { quantity_type, .. } = &self;
-        let Unit {
-            name: unit_name,
-            factor,
-            ..
-        } = unit;
-        let name = &float_type.name;
+        if !storage_type.has_constructor(unit) {
+            return quote! {};
+        }
+        let Some(Constructor {
+            fn_args,
+            value,
+            scalar,
+        }) = storage_type.constructor()
+        else {
+            return quote! {};
+        };
+        let impl_generics = storage_type.impl_generics();
+        let name = storage_type.name();
+        let unit_name = &unit.name;
+        let factor = factor_literal(unit.factor);
+        let scale = |value: TokenStream| match &scalar {
+            Some(scalar) => quote! { #value * (#factor as #scalar) },
+            None => quote! { diman::StorageType::mul_f64(&#value, #factor) },
+        };
         let span = self.dimension_type.span();
-        quote_spanned! {span =>
-            impl #quantity_type<#name, {#quantity_dimension}> {
-                pub fn #unit_name(val: #name) -> #quantity_type<#name, {#quantity_dimension}> {
-                    #quantity_type::<#name, {#quantity_dimension}>(val * (#factor as #name))
+        match unit.kind {
+            UnitKind::Linear => {
+                let value = scale(value);
+                quote_spanned! {span =>
+                    impl #impl_generics #quantity_type<#name, {#quantity_dimension}> {
+                        pub fn #unit_name(#fn_args) -> #quantity_type<#name, {#quantity_dimension}> {
+                            #quantity_type::<#name, {#quantity_dimension}>(#value)
+                        }
+                    }
                 }
             }
+            UnitKind::Affine { offset } => {
+                let difference_name = format_ident!("{}_difference", unit_name);
+                let point_value = scale(match &scalar {
+                    Some(scalar) => quote! { (#value + (#offset as #scalar)) },
+                    None => quote! { diman::StorageType::add_f64(&#value, #offset) },
+                });
+                let difference_value = scale(value);
+                quote_spanned! {span =>
+                    impl #impl_generics #quantity_type<#name, {#quantity_dimension}> {
+                        pub fn #unit_name(#fn_args) -> QuantityPoint<#name, {#quantity_dimension}> {
+                            QuantityPoint(#quantity_type::<#name, {#quantity_dimension}>(#point_value))
+                        }
+
+                        /// Construct a difference of two points given in this unit,
+                        /// to which the offset of the unit does not apply.
+                        pub fn #difference_name(#fn_args) -> #quantity_type<#name, {#quantity_dimension}> {
+                            #quantity_type::<#name, {#quantity_dimension}>(#difference_value)
+                        }
+                    }
+                }
+            }
+            UnitKind::Logarithmic { .. } => quote! {},
         }
     }
 
-    fn vector_unit_constructor(
+    /// Logarithmic units are only defined for float types.
+    fn logarithmic_unit_constructor(
         &self,
-        vector_type: &VectorType,
+        float_type: &FloatType,
         unit: &Unit,
         quantity_dimension: &TokenStream,
     ) -> TokenStream {
         let Defs { quantity_type, .. } = &self;
-        let Unit {
-            name: unit_name,
-            factor,
-            ..
-        } = unit;
-        let VectorType {
-            name,
-            float_type,
-            num_dims,
-            ..
-        } = &vector_type;
-        let float_type = &float_type.name;
-        let fn_args = match num_dims {
-            2 => quote! { x: #float_type, y: #float_type },
-            3 => quote! { x: #float_type, y: #float_type, z: #float_type },
-            _ => unreachable!(),
-        };
-        let call_args = match num_dims {
-            2 => quote! { x, y },
-            3 => quote! { x, y, z },
-            _ => unreachable!(),
+        let name = &float_type.name;
+        let UnitKind::Logarithmic { base, scale } = unit.kind else {
+            return quote! {};
         };
+        let factor = factor_literal(unit.factor);
+        let constructor_name = format_ident!("from_{}", unit.name);
+        let conversion_method_name = format_ident!("in_{}", unit.name);
+        let log = format_ident!("{}", base.function_name());
+        let base = base.base();
         let span = self.dimension_type.span();
-        quote_spanned! {span => 
+        quote_spanned! {span =>
             impl #quantity_type<#name, {#quantity_dimension}> {
-                pub fn #unit_name(#fn_args) -> #quantity_type<#name, {#quantity_dimension}> {
-                    #quantity_type::<#name, {#quantity_dimension}>(#name::new(#call_args) * (#factor as #float_type))
+                pub fn #constructor_name(val: #name) -> #quantity_type<#name, {#quantity_dimension}> {
+                    #quantity_type::<#name, {#quantity_dimension}>((#base as #name).powf(val / (#scale as #name)) * (#factor as #name))
+                }
+
+                pub fn #conversion_method_name(self) -> #name {
+                    (#scale as #name) * (self.0 / (#factor as #name)).#log()
                 }
             }
         }
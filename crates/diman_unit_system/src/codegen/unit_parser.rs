@@ -0,0 +1,373 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    storage_types::{FloatType, IntType},
+    types::{Defs, UnitKind},
+};
+
+use super::utils::{factor_literal, join};
+
+impl Defs {
+    pub fn unit_parser(&self) -> TokenStream {
+        join([
+            self.unit_parser_helpers(),
+            self.unit_parser_floats(),
+            self.unit_parser_ints(),
+        ])
+    }
+
+    fn unit_parser_helpers(&self) -> TokenStream {
+        let Defs { dimension_type, .. } = self;
+        let non_linear_unit_arms = self.non_linear_unit_arms();
+        quote! {
+            /// The reason why parsing a quantity or unit string failed.
+            #[derive(Clone, Debug, PartialEq, Eq)]
+            pub enum UnitParseErrorKind {
+                /// The string to parse was empty.
+                Empty,
+                /// The numerical value could not be parsed.
+                InvalidNumber,
+                /// The unit string is not a valid unit expression.
+                InvalidSyntax,
+                /// No unit with the given symbol exists.
+                UnknownUnit,
+                /// A unit with an offset, such as degrees Celsius, or a logarithmic
+                /// unit was used as part of a unit expression instead of on its own.
+                NonLinearUnit,
+                /// The exponent of a unit could not be parsed.
+                InvalidExponent,
+                /// A fractional exponent was applied to a unit whose dimension
+                /// cannot be raised to this power.
+                FractionalDimension,
+                /// The value of a quantity with integer storage is not an
+                /// integer in base units, or does not fit into the storage type.
+                NonIntegerValue,
+                /// The unit was parsed, but its dimension does not match
+                /// the dimension of the quantity.
+                DimensionMismatch(DimensionMismatch),
+            }
+
+            /// The error returned when parsing a quantity or unit string fails.
+            /// Contains the token which could not be parsed and its (one-based) column.
+            #[derive(Clone, Debug, PartialEq, Eq)]
+            pub struct UnitParseError {
+                pub token: String,
+                pub column: usize,
+                pub kind: UnitParseErrorKind,
+            }
+
+            impl UnitParseError {
+                fn new(token: &str, column: usize, kind: UnitParseErrorKind) -> Self {
+                    Self {
+                        token: token.to_string(),
+                        column,
+                        kind,
+                    }
+                }
+            }
+
+            impl std::fmt::Display for UnitParseError {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match &self.kind {
+                        UnitParseErrorKind::Empty => write!(f, "unable to parse empty string"),
+                        UnitParseErrorKind::InvalidNumber => {
+                            write!(f, "unable to parse numerical value: {}", self.token)
+                        }
+                        UnitParseErrorKind::InvalidSyntax => {
+                            write!(f, "unexpected token: \"{}\"", self.token)
+                        }
+                        UnitParseErrorKind::UnknownUnit => write!(f, "unknown unit: {}", self.token),
+                        UnitParseErrorKind::NonLinearUnit => {
+                            write!(f, "non-linear unit can only be used on its own: {}", self.token)
+                        }
+                        UnitParseErrorKind::InvalidExponent => {
+                            write!(f, "unable to parse unit exponent: {}", self.token)
+                        }
+                        UnitParseErrorKind::FractionalDimension => {
+                            write!(f, "fractional power results in a non-integer dimension: {}", self.token)
+                        }
+                        UnitParseErrorKind::NonIntegerValue => {
+                            write!(f, "value is not representable by an integer in base units: {}", self.token)
+                        }
+                        UnitParseErrorKind::DimensionMismatch(mismatch) => {
+                            write!(f, "{} in string: {}", mismatch, self.token)
+                        }
+                    }
+                    .and_then(|_| write!(f, " (at column {})", self.column))
+                }
+            }
+
+            impl std::error::Error for UnitParseError {}
+
+            /// Parse a unit expression, such as `km/s`, `kg m^2 s^-2` or `W/(m² K)`, into
+            /// the resulting dimension and the factor with respect to the base units.
+            pub fn parse_unit_expression(unit_str: &str) -> Result<(#dimension_type, f64), UnitParseError> {
+                parse_unit_expression_at(unit_str, 1)
+            }
+
+            /// Parse a unit expression which starts at the given column of the
+            /// string in which it is contained.
+            fn parse_unit_expression_at(unit_str: &str, column: usize) -> Result<(#dimension_type, f64), UnitParseError> {
+                let powers = diman::unit_string::parse_unit_string(unit_str).map_err(|e| {
+                    let kind = match e.kind {
+                        diman::unit_string::UnitStringErrorKind::InvalidExponent => {
+                            UnitParseErrorKind::InvalidExponent
+                        }
+                        _ => UnitParseErrorKind::InvalidSyntax,
+                    };
+                    UnitParseError::new(&e.token, e.column + column - 1, kind)
+                })?;
+                let mut total_dimension = #dimension_type::none();
+                let mut total_factor = 1.0;
+                for power in powers {
+                    let power_column = power.column + column - 1;
+                    let (dimension, _, factor) = __diman_unit_names::UNITS
+                        .iter()
+                        .find(|(_, known_unit_name, _)| &power.symbol == known_unit_name)
+                        .ok_or_else(|| {
+                            let kind = if non_linear_unit_to_base(power.symbol, 1.0).is_some() {
+                                UnitParseErrorKind::NonLinearUnit
+                            } else {
+                                UnitParseErrorKind::UnknownUnit
+                            };
+                            UnitParseError::new(power.symbol, power_column, kind)
+                        })?;
+                    let invalid_exponent = || {
+                        UnitParseError::new(
+                            power.symbol,
+                            power_column,
+                            UnitParseErrorKind::InvalidExponent,
+                        )
+                    };
+                    let dimension = dimension
+                        .clone()
+                        .dimension_checked_powi(power.numerator)
+                        .ok_or_else(invalid_exponent)?
+                        .dimension_checked_root(power.denominator)
+                        .ok_or_else(|| {
+                            UnitParseError::new(
+                                power.symbol,
+                                power_column,
+                                UnitParseErrorKind::FractionalDimension,
+                            )
+                        })?;
+                    let mut factor = factor.powi(power.numerator);
+                    if power.denominator != 1 {
+                        factor = factor.powf(1.0 / power.denominator as f64);
+                    }
+                    total_dimension = total_dimension
+                        .dimension_checked_mul(dimension)
+                        .ok_or_else(invalid_exponent)?;
+                    total_factor *= factor;
+                }
+                Ok((total_dimension, total_factor))
+            }
+
+            /// If the unit string is the symbol of a unit with an offset or of
+            /// a logarithmic unit, convert the value given in this unit into base units.
+            #[allow(unused_variables)]
+            fn non_linear_unit_to_base(unit_str: &str, value: f64) -> Option<(#dimension_type, f64)> {
+                match unit_str.trim() {
+                    #non_linear_unit_arms
+                    _ => None,
+                }
+            }
+
+            /// Split a quantity string such as `10 km/s` into its numerical part
+            /// and its unit part, along with the columns at which they start.
+            fn split_quantity_str(value: &str) -> Result<((&str, usize), (&str, usize)), UnitParseError> {
+                let column_of = |part: &str| {
+                    let offset = part.as_ptr() as usize - value.as_ptr() as usize;
+                    value[..offset].chars().count() + 1
+                };
+                let trimmed = value.trim();
+                if trimmed.is_empty() {
+                    return Err(UnitParseError::new(value, 1, UnitParseErrorKind::Empty));
+                }
+                let (number, unit) = trimmed
+                    .split_once(char::is_whitespace)
+                    .unwrap_or((trimmed, &trimmed[trimmed.len()..]));
+                Ok(((number, column_of(number)), (unit, column_of(unit))))
+            }
+
+            /// The error for a quantity string whose unit has a different dimension
+            /// than the quantity, pointing at the unit. Values without a unit are
+            /// dimensionless, so the error points at the value instead.
+            fn dimension_mismatch_error(value: &str, mismatch: DimensionMismatch) -> UnitParseError {
+                let kind = UnitParseErrorKind::DimensionMismatch(mismatch);
+                match split_quantity_str(value) {
+                    Ok(((number, number_column), _)) if value.trim() == number => {
+                        UnitParseError::new(number, number_column, kind)
+                    }
+                    Ok((_, (unit, unit_column))) => UnitParseError::new(unit, unit_column, kind),
+                    Err(_) => UnitParseError::new(value.trim(), 1, kind),
+                }
+            }
+        }
+    }
+
+    /// The match arms converting a value given in a unit with
+    /// an offset or in a logarithmic unit into base units.
+    fn non_linear_unit_arms(&self) -> TokenStream {
+        self.units
+            .iter()
+            .filter_map(|unit| {
+                let symbol = unit.symbol.as_ref()?;
+                let dimension = self.get_dimension_expr(&unit.dimension);
+                let factor = factor_literal(unit.factor);
+                let value = match unit.kind {
+                    UnitKind::Linear => return None,
+                    UnitKind::Affine { offset } => quote! { (value + #offset) * #factor },
+                    UnitKind::Logarithmic { base, scale } => {
+                        let base = base.base();
+                        quote! { #base.powf(value / #scale) * #factor }
+                    }
+                };
+                Some(quote! {
+                    #symbol => Some((#dimension, #value)),
+                })
+            })
+            .collect()
+    }
+
+    fn unit_parser_floats(&self) -> TokenStream {
+        self.float_types()
+            .iter()
+            .map(|float_type| self.unit_parser_float(float_type))
+            .collect()
+    }
+
+    fn unit_parser_float(&self, float_type: &FloatType) -> TokenStream {
+        let Defs {
+            dimension_type,
+            quantity_type,
+            ..
+        } = self;
+        let float_type = &float_type.name;
+        quote! {
+            impl std::str::FromStr for DynQuantity<#float_type> {
+                type Err = UnitParseError;
+
+                fn from_str(value: &str) -> Result<Self, Self::Err> {
+                    let ((numerical_value_str, number_column), (unit_str, unit_column)) =
+                        split_quantity_str(value)?;
+                    let numerical_value = numerical_value_str.parse::<#float_type>().map_err(|_| {
+                        UnitParseError::new(
+                            numerical_value_str,
+                            number_column,
+                            UnitParseErrorKind::InvalidNumber,
+                        )
+                    })?;
+                    if let Some((dimension, value)) = non_linear_unit_to_base(unit_str, numerical_value as f64) {
+                        return Ok(DynQuantity::new_unchecked(value as #float_type, dimension));
+                    }
+                    let (dimension, factor) = parse_unit_expression_at(unit_str, unit_column)?;
+                    Ok(DynQuantity::new_unchecked(
+                        numerical_value * (factor as #float_type),
+                        dimension,
+                    ))
+                }
+            }
+
+            impl<const D: #dimension_type> std::str::FromStr for #quantity_type<#float_type, D> {
+                type Err = UnitParseError;
+
+                fn from_str(value: &str) -> Result<Self, Self::Err> {
+                    value
+                        .parse::<DynQuantity<#float_type>>()?
+                        .try_into()
+                        .map_err(|e| dimension_mismatch_error(value, e))
+                }
+            }
+        }
+    }
+
+    fn unit_parser_ints(&self) -> TokenStream {
+        self.int_types()
+            .iter()
+            .map(|int_type| self.unit_parser_int(int_type))
+            .collect()
+    }
+
+    /// Integer values are converted to base units with checked integer
+    /// arithmetic if the factor of the unit or its inverse is an integer,
+    /// so that they are exact for the whole range of the storage type.
+    /// Other values are converted as floats, after which they have to be
+    /// an integer which fits into the storage type.
+    fn unit_parser_int(&self, int_type: &IntType) -> TokenStream {
+        let Defs {
+            dimension_type,
+            quantity_type,
+            ..
+        } = self;
+        let int_type = &int_type.name;
+        quote! {
+            impl std::str::FromStr for DynQuantity<#int_type> {
+                type Err = UnitParseError;
+
+                fn from_str(value: &str) -> Result<Self, Self::Err> {
+                    /// The integer closest to `value`, if `value` is an integer
+                    /// up to rounding errors and fits into the storage type.
+                    fn to_int(value: f64) -> Option<#int_type> {
+                        let rounded = value.round();
+                        let is_int = (value - rounded).abs() <= 1e-9 * rounded.abs().max(1.0);
+                        (is_int && rounded >= #int_type::MIN as f64 && rounded <= #int_type::MAX as f64)
+                            .then_some(rounded as #int_type)
+                    }
+
+                    let ((numerical_value_str, number_column), (unit_str, unit_column)) =
+                        split_quantity_str(value)?;
+                    let int_value = numerical_value_str.parse::<#int_type>().ok();
+                    let numerical_value = match int_value {
+                        Some(int_value) => int_value as f64,
+                        None => numerical_value_str.parse::<f64>().map_err(|_| {
+                            UnitParseError::new(
+                                numerical_value_str,
+                                number_column,
+                                UnitParseErrorKind::InvalidNumber,
+                            )
+                        })?,
+                    };
+                    let (dimension, base_value) = match non_linear_unit_to_base(unit_str, numerical_value) {
+                        Some((dimension, base_value)) => (dimension, to_int(base_value)),
+                        None => {
+                            let (dimension, factor) = parse_unit_expression_at(unit_str, unit_column)?;
+                            let base_value = match (int_value, to_int(factor), to_int(1.0 / factor)) {
+                                (Some(int_value), Some(factor), _) => int_value.checked_mul(factor),
+                                (Some(int_value), None, Some(divisor)) => {
+                                    if int_value.checked_rem(divisor) == Some(0) {
+                                        int_value.checked_div(divisor)
+                                    } else {
+                                        None
+                                    }
+                                }
+                                _ => to_int(numerical_value * factor),
+                            };
+                            (dimension, base_value)
+                        }
+                    };
+                    let base_value = base_value.ok_or_else(|| {
+                        UnitParseError::new(
+                            value.trim(),
+                            1,
+                            UnitParseErrorKind::NonIntegerValue,
+                        )
+                    })?;
+                    Ok(DynQuantity::new_unchecked(base_value, dimension))
+                }
+            }
+
+            impl<const D: #dimension_type> std::str::FromStr for #quantity_type<#int_type, D> {
+                type Err = UnitParseError;
+
+                fn from_str(value: &str) -> Result<Self, Self::Err> {
+                    value
+                        .parse::<DynQuantity<#int_type>>()?
+                        .try_into()
+                        .map_err(|e| dimension_mismatch_error(value, e))
+                }
+            }
+        }
+    }
+}
@@ -1,5 +1,14 @@
 use proc_macro2::TokenStream;
+use quote::quote;
 
 pub fn join<const D: usize>(streams: [TokenStream; D]) -> TokenStream {
     streams.into_iter().collect()
 }
+
+/// The factor of a unit or constant. It is written via its bits, since
+/// factors such as `2 * pi` would otherwise be reported by clippy's
+/// `approx_constant` lint in the code of the unit system.
+pub fn factor_literal(factor: f64) -> TokenStream {
+    let bits = factor.to_bits();
+    quote! { f64::from_bits(#bits) }
+}
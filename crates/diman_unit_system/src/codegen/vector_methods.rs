@@ -1,14 +1,107 @@
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
+use syn::Type;
 
-use crate::{storage_types::VectorType, types::Defs};
+use crate::{
+    storage_types::{vector_components, IntVectorType, VectorType},
+    types::Defs,
+};
 
 impl Defs {
     pub fn vector_methods(&self) -> TokenStream {
-        self.vector_types()
+        let float_vector_methods: TokenStream = self
+            .vector_types()
             .iter()
             .map(|vector_type| self.impl_vector_methods(vector_type))
-            .collect()
+            .collect();
+        let int_vector_methods: TokenStream = self
+            .int_vector_types()
+            .iter()
+            .map(|vector_type| self.impl_int_vector_methods(vector_type))
+            .collect();
+        quote! {
+            #float_vector_methods
+            #int_vector_methods
+        }
+    }
+
+    /// The constructors and accessors of the components, which are
+    /// shared between float and integer vectors.
+    fn vector_component_methods(
+        &self,
+        vector_type_name: &Type,
+        scalar_type: &Type,
+        num_dims: usize,
+    ) -> TokenStream {
+        let Defs { quantity_type, .. } = self;
+        let components = vector_components(num_dims);
+        let new_args = components.iter().map(|component| {
+            quote! { #component: #quantity_type<#scalar_type, D> }
+        });
+        let new_values = components.iter().map(|component| {
+            quote! { #component.value_unchecked() }
+        });
+        let component_methods: TokenStream = components
+            .iter()
+            .map(|component| {
+                let new_component = format_ident!("new_{}", component);
+                let set_component = format_ident!("set_{}", component);
+                let new_value = format_ident!("new_{}", component);
+                let unit_vector = format_ident!("{}", component.to_string().to_uppercase());
+                quote! {
+                    pub fn #new_component(q: #quantity_type<#scalar_type, D>) -> #quantity_type<#vector_type_name, D> {
+                        q * <#vector_type_name>::#unit_vector
+                    }
+
+                    pub fn #component(&self) -> #quantity_type<#scalar_type, D> {
+                        #quantity_type(self.0.#component)
+                    }
+
+                    pub fn #set_component(&mut self, #new_value: #quantity_type<#scalar_type, D>) {
+                        self.0.#component = #new_value.value_unchecked();
+                    }
+                }
+            })
+            .collect();
+        quote! {
+            pub fn new(#(#new_args),*) -> Self {
+                Self(<#vector_type_name>::new(#(#new_values),*))
+            }
+
+            #component_methods
+
+            pub fn zero() -> Self {
+                Self(<#vector_type_name>::ZERO)
+            }
+
+            pub fn min(self, rhs: Self) -> Self {
+                Self(self.0.min(rhs.0))
+            }
+
+            pub fn max(self, rhs: Self) -> Self {
+                Self(self.0.max(rhs.0))
+            }
+        }
+    }
+
+    /// The cross product, which only exists for three-dimensional vectors.
+    fn vector_cross_method(&self, vector_type_name: &Type, num_dims: usize) -> TokenStream {
+        let Defs {
+            dimension_type,
+            quantity_type,
+            ..
+        } = self;
+        if num_dims != 3 {
+            return quote! {};
+        }
+        quote! {
+            pub fn cross<const DR: #dimension_type>(
+                self,
+                rhs: #quantity_type<#vector_type_name, DR>,
+            ) -> #quantity_type<#vector_type_name, { D.dimension_mul(DR) }> {
+                #quantity_type(self.0.cross(rhs.0))
+            }
+        }
     }
 
     pub fn impl_vector_methods(&self, vector_type: &VectorType) -> TokenStream {
@@ -24,127 +117,146 @@ impl Defs {
             ..
         } = vector_type;
         let float_type = &float_type.name;
-        let new_z_impl = if *num_dims == 3 {
-            quote! {
-                pub fn new_z(q: #quantity_type<#float_type, D>) -> #quantity_type<#vector_type_name, D> {
-                    q * <#vector_type_name>::Z
-                }
-            }
-        } else {
-            quote! {}
-        };
-        let z_impl = if *num_dims == 3 {
+        let component_methods =
+            self.vector_component_methods(vector_type_name, float_type, *num_dims);
+        let cross_method = self.vector_cross_method(vector_type_name, *num_dims);
+        // glam does not provide `angle_between` for four-dimensional vectors.
+        let angle_between = if *num_dims == 4 {
             quote! {
-                pub fn z(&self) -> #quantity_type<#float_type, D> {
-                    #quantity_type(self.0.z)
-                }
-            }
-        } else {
-            quote! {}
-        };
-        let set_z_impl = if *num_dims == 3 {
-            quote! {
-                pub fn set_z(&mut self, new_z: #quantity_type<#float_type, D>) {
-                    self.0.z = new_z.value_unchecked();
-                }
+                (self.0.dot(rhs.0) / (self.0.length_squared() * rhs.0.length_squared()).sqrt())
+                    .clamp(-1.0, 1.0)
+                    .acos()
             }
         } else {
-            quote! {}
-        };
-        let new_impl = if *num_dims == 3 {
-            quote! {
-                pub fn new(
-                    x: #quantity_type<#float_type, D>,
-                    y: #quantity_type<#float_type, D>,
-                    z: #quantity_type<#float_type, D>,
-                ) -> Self {
-                    Self(<#vector_type_name>::new(x.value_unchecked(), y.value_unchecked(), z.value_unchecked()))
-                }
-            }
-        } else {
-            quote! {
-                pub fn new(
-                    x: #quantity_type<#float_type, D>,
-                    y: #quantity_type<#float_type, D>,
-                ) -> Self {
-                    Self(<#vector_type_name>::new(x.value_unchecked(), y.value_unchecked()))
-                }
-            }
+            quote! { self.0.angle_between(rhs.0) }
         };
         quote! {
             impl<const D: #dimension_type> #quantity_type<#vector_type_name, D> {
-                #new_impl
+                #component_methods
 
-                pub fn new_x(q: #quantity_type<#float_type, D>) -> #quantity_type<#vector_type_name, D> {
-                    q * <#vector_type_name>::X
+                pub fn length(&self) -> #quantity_type<#float_type, D> {
+                    #quantity_type::<#float_type, D>(self.0.length())
                 }
 
-                pub fn new_y(q: #quantity_type<#float_type, D>) -> #quantity_type<#vector_type_name, D> {
-                    q * <#vector_type_name>::Y
+                pub fn distance(&self, other: &Self) -> #quantity_type<#float_type, D> {
+                    #quantity_type::<#float_type, D>(self.0.distance(other.0))
                 }
 
-                #new_z_impl
+                pub fn distance_squared(
+                    &self,
+                    other: &Self,
+                ) -> #quantity_type<#float_type, { D.dimension_powi(2) }>
+                where
+                    #quantity_type<#float_type, { D.dimension_powi(2) }>:,
+                {
+                    #quantity_type::<#float_type, { D.dimension_powi(2) }>(self.0.distance_squared(other.0))
+                }
 
-                pub fn x(&self) -> #quantity_type<#float_type, D> {
-                    #quantity_type(self.0.x)
+                pub fn normalize(&self) -> #quantity_type<#vector_type_name, { #dimension_type::none() }> {
+                    #quantity_type::<#vector_type_name, { #dimension_type::none() }>(self.0.normalize())
                 }
 
-                pub fn y(&self) -> #quantity_type<#float_type, D> {
-                    #quantity_type(self.0.y)
+                pub fn dot<const DR: #dimension_type>(
+                    self,
+                    rhs: #quantity_type<#vector_type_name, DR>,
+                ) -> #quantity_type<#float_type, { D.dimension_mul(DR) }> {
+                    #quantity_type(self.0.dot(rhs.0))
                 }
 
-                #z_impl
+                #cross_method
 
-                pub fn set_x(&mut self, new_x: #quantity_type<#float_type, D>) {
-                    self.0.x = new_x.value_unchecked();
+                /// Linear interpolation between `self` and `rhs`, where
+                /// `s = 0` results in `self` and `s = 1` in `rhs`.
+                pub fn lerp(self, rhs: Self, s: #float_type) -> Self {
+                    Self(self.0.lerp(rhs.0, s))
                 }
 
-                pub fn set_y(&mut self, new_y: #quantity_type<#float_type, D>) {
-                    self.0.y = new_y.value_unchecked();
+                /// The projection of `self` onto the direction of `rhs`.
+                pub fn project_onto<const DR: #dimension_type>(
+                    self,
+                    rhs: #quantity_type<#vector_type_name, DR>,
+                ) -> Self {
+                    Self(self.0.project_onto(rhs.0))
                 }
 
-                #set_z_impl
-
-                pub fn zero() -> Self {
-                    Self(<#vector_type_name>::ZERO)
+                /// The part of `self` which is orthogonal to `rhs`.
+                pub fn reject_from<const DR: #dimension_type>(
+                    self,
+                    rhs: #quantity_type<#vector_type_name, DR>,
+                ) -> Self {
+                    Self(self.0.reject_from(rhs.0))
                 }
 
-                pub fn min(self, rhs: Self) -> Self {
-                    Self(self.0.min(rhs.0))
+                /// The angle between the two vectors in radians.
+                pub fn angle_between<const DR: #dimension_type>(
+                    self,
+                    rhs: #quantity_type<#vector_type_name, DR>,
+                ) -> #quantity_type<#float_type, { #dimension_type::none() }> {
+                    #quantity_type(#angle_between)
                 }
 
-                pub fn max(self, rhs: Self) -> Self {
-                    Self(self.0.max(rhs.0))
+                pub fn clamp_length(
+                    self,
+                    min: #quantity_type<#float_type, D>,
+                    max: #quantity_type<#float_type, D>,
+                ) -> Self {
+                    Self(self.0.clamp_length(min.value_unchecked(), max.value_unchecked()))
                 }
 
-                pub fn length(&self) -> #quantity_type<#float_type, D> {
-                    #quantity_type::<#float_type, D>(self.0.length())
+                /// Whether the absolute difference of all components of
+                /// the two vectors is at most `max_abs_diff`.
+                pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: #quantity_type<#float_type, D>) -> bool {
+                    self.0.abs_diff_eq(rhs.0, max_abs_diff.value_unchecked())
                 }
+            }
+        }
+    }
 
-                pub fn distance(&self, other: &Self) -> #quantity_type<#float_type, D> {
-                    #quantity_type::<#float_type, D>(self.0.distance(other.0))
+    pub fn impl_int_vector_methods(&self, vector_type: &IntVectorType) -> TokenStream {
+        let Defs {
+            dimension_type,
+            quantity_type,
+            ..
+        } = self;
+        let IntVectorType {
+            name: vector_type_name,
+            int_type,
+            num_dims,
+            ..
+        } = vector_type;
+        let int_type = &int_type.name;
+        let component_methods =
+            self.vector_component_methods(vector_type_name, int_type, *num_dims);
+        let cross_method = self.vector_cross_method(vector_type_name, *num_dims);
+        quote! {
+            impl<const D: #dimension_type> #quantity_type<#vector_type_name, D> {
+                #component_methods
+
+                pub fn length_squared(&self) -> #quantity_type<#int_type, { D.dimension_powi(2) }>
+                where
+                    #quantity_type<#int_type, { D.dimension_powi(2) }>:,
+                {
+                    #quantity_type::<#int_type, { D.dimension_powi(2) }>(self.0.length_squared())
                 }
 
                 pub fn distance_squared(
                     &self,
                     other: &Self,
-                ) -> #quantity_type<#float_type, { D.dimension_powi(2) }>
+                ) -> #quantity_type<#int_type, { D.dimension_powi(2) }>
                 where
-                    #quantity_type<#float_type, { D.dimension_powi(2) }>:,
+                    #quantity_type<#int_type, { D.dimension_powi(2) }>:,
                 {
-                    #quantity_type::<#float_type, { D.dimension_powi(2) }>(self.0.distance_squared(other.0))
+                    #quantity_type::<#int_type, { D.dimension_powi(2) }>(self.0.distance_squared(other.0))
                 }
 
-                pub fn normalize(&self) -> #quantity_type<#vector_type_name, { #dimension_type::none() }> {
-                    #quantity_type::<#vector_type_name, { #dimension_type::none() }>(self.0.normalize())
-                }
-
-                pub fn dot<const DR: Dimension>(
+                pub fn dot<const DR: #dimension_type>(
                     self,
-                    rhs: Quantity<#vector_type_name, DR>,
-                ) -> #quantity_type<#float_type, { D.dimension_mul(DR) }> {
+                    rhs: #quantity_type<#vector_type_name, DR>,
+                ) -> #quantity_type<#int_type, { D.dimension_mul(DR) }> {
                     #quantity_type(self.0.dot(rhs.0))
                 }
+
+                #cross_method
             }
         }
     }
@@ -0,0 +1,178 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    token::Brace,
+    *,
+};
+
+use crate::{parse::types::Factor, verify::Verify};
+
+/// A quantity type together with its dimension type,
+/// as in `si::Quantity<si::Dimension>`.
+struct System {
+    quantity_type: Path,
+    dimension_type: Type,
+}
+
+/// Maps a base dimension of the source system onto one of the
+/// target system, as in `length => 100 * length`. The factor is the
+/// value of the base unit of the source in the base unit of the target.
+struct Mapping {
+    source: Ident,
+    factor: Option<Factor>,
+    target: Ident,
+}
+
+struct Conversion {
+    source: System,
+    target: System,
+    mappings: Vec<Mapping>,
+}
+
+impl Parse for System {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let quantity_type = Path::parse_mod_style(input)?;
+        let _: Token![<] = input.parse()?;
+        let dimension_type = input.parse()?;
+        let _: Token![>] = input.parse()?;
+        Ok(Self {
+            quantity_type,
+            dimension_type,
+        })
+    }
+}
+
+impl Parse for Mapping {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let source = input.parse()?;
+        let _: Token![=>] = input.parse()?;
+        let factor = if input.peek(Lit) {
+            let factor = input.parse()?;
+            let _: Token![*] = input.parse()?;
+            Some(factor)
+        } else {
+            None
+        };
+        let target = input.parse()?;
+        Ok(Self {
+            source,
+            factor,
+            target,
+        })
+    }
+}
+
+impl Parse for Conversion {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let source = input.parse()?;
+        let _: Token![,] = input.parse()?;
+        let target = input.parse()?;
+        let _: Token![,] = input.parse()?;
+        let content;
+        let _: Brace = braced!(content in input);
+        let mappings: Punctuated<Mapping, Token![,]> = content.parse_terminated(Mapping::parse)?;
+        Ok(Self {
+            source,
+            target,
+            mappings: mappings.into_iter().collect(),
+        })
+    }
+}
+
+fn check_no_duplicates<'a>(idents: impl Iterator<Item = &'a Ident>) -> Result<()> {
+    let mut seen: Vec<&Ident> = vec![];
+    for ident in idents {
+        if seen.contains(&ident) {
+            return Err(Error::new(
+                ident.span(),
+                format!("Dimension \"{}\" is mapped more than once.", ident),
+            ));
+        }
+        seen.push(ident);
+    }
+    Ok(())
+}
+
+impl Conversion {
+    fn code_gen(self) -> Result<TokenStream> {
+        check_no_duplicates(self.mappings.iter().map(|mapping| &mapping.source))?;
+        check_no_duplicates(self.mappings.iter().map(|mapping| &mapping.target))?;
+        let source_quantity = &self.source.quantity_type;
+        let source_dimension = &self.source.dimension_type;
+        let target_quantity = &self.target.quantity_type;
+        let target_dimension = &self.target.dimension_type;
+        let mut remove_mapped = quote! {};
+        let mut set_targets = quote! {};
+        let mut scale_factors = quote! {};
+        for mapping in self.mappings {
+            let getter = format_ident!("{}_ratio", mapping.source);
+            let source_setter = format_ident!("with_{}_ratio", mapping.source);
+            let target_setter = format_ident!("with_{}_ratio", mapping.target);
+            remove_mapped.extend(quote! { .#source_setter(0, 1) });
+            set_targets.extend(quote! {
+                .#target_setter(dimension.#getter().0, dimension.#getter().1)
+            });
+            if let Some(factor) = mapping.factor {
+                let factor = factor.verify()?;
+                scale_factors.extend(quote! {
+                    let (numerator, denominator) = dimension.#getter();
+                    scale *= diman::Ratio::new(numerator, denominator).pow_f64(#factor);
+                });
+            }
+        }
+        Ok(quote! {
+            const _: () = {
+                const fn convert_dimension(dimension: #source_dimension) -> #target_dimension {
+                    let converted = #target_dimension::none()#set_targets;
+                    let unmapped = dimension #remove_mapped;
+                    if !unmapped.is_none() {
+                        panic!("Cannot convert a quantity whose dimension contains a base dimension without a mapping.");
+                    }
+                    converted
+                }
+
+                /// The value of the base unit of the source
+                /// dimension in the base unit of the target dimension.
+                #[allow(unused_mut, unused_variables)]
+                const fn scale(dimension: #source_dimension) -> f64 {
+                    let mut scale = 1.0f64;
+                    #scale_factors
+                    scale
+                }
+
+                /// Evaluates the scale once per dimension, at compile time.
+                struct Scale<const D: #source_dimension>;
+
+                impl<const D: #source_dimension> Scale<D> {
+                    const VALUE: f64 = scale(D);
+                }
+
+                impl<S: diman::StorageType, const D: #source_dimension> From<#source_quantity<S, D>>
+                    for #target_quantity<S, { convert_dimension(D) }>
+                {
+                    fn from(quantity: #source_quantity<S, D>) -> Self {
+                        let value = quantity.value_unchecked();
+                        let scale = Scale::<D>::VALUE;
+                        if scale == 1.0 {
+                            Self::new_unchecked(value)
+                        } else {
+                            Self::new_unchecked(value.mul_f64(scale))
+                        }
+                    }
+                }
+            };
+        })
+    }
+}
+
+pub(crate) fn unit_system_conversion_impl(
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let conversion = parse_macro_input!(input as Conversion);
+    conversion
+        .code_gen()
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
@@ -1,11 +1,23 @@
 use quote::quote;
-use syn::DeriveInput;
+use syn::{parse_macro_input, DeriveInput};
+
+use crate::verify::Errors;
 
 const ALLOWED_TYPES: &[&str] = &["i8", "i32", "i64"];
 
 pub(crate) fn dimension_impl(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let input: DeriveInput = syn::parse(input).unwrap();
-    let methods_impl: proc_macro2::TokenStream = dimension_methods_impl(&input).into();
+    let input = parse_macro_input!(input as DeriveInput);
+    let methods_impl = match dimension_methods_impl(&input) {
+        Ok(methods_impl) => methods_impl,
+        Err(error) => {
+            let error = error.to_compile_error();
+            return quote! {
+                #input
+                #error
+            }
+            .into();
+        }
+    };
     let output = quote! {
         #[derive(PartialEq, Eq, Clone, Debug, ::std::marker::ConstParamTy)]
         #input
@@ -15,112 +27,373 @@ pub(crate) fn dimension_impl(input: proc_macro::TokenStream) -> proc_macro::Toke
     output.into()
 }
 
-pub(crate) fn dimension_methods_impl(input: &DeriveInput) -> proc_macro::TokenStream {
-    let type_name = &input.ident;
-    let (impl_generics, type_generics, where_clause) = &input.generics.split_for_impl();
-    let panic_unexpected_type =
-        || panic!("Found unexpected field type while deriving diman_dimension methods.");
-    let mut field_names = vec![];
-    if let syn::Data::Struct(s) = &input.data {
-        if let syn::Fields::Named(fields) = &s.fields {
-            for f in fields.named.iter() {
-                if let syn::Type::Path(ref type_name) = f.ty {
-                    if type_name.path.segments.len() != 1 {
-                        panic_unexpected_type();
-                    } else {
-                        let only_segment_in_path = &type_name.path.segments[0];
-                        let type_name = only_segment_in_path.ident.to_string();
-                        if !ALLOWED_TYPES.contains(&type_name.as_str()) {
-                            panic_unexpected_type();
-                        }
-                    }
-                } else {
-                    panic_unexpected_type();
+/// The type of a field of a dimension struct.
+enum FieldType {
+    /// An integer exponent. Contains the integer type.
+    Int(syn::Ident),
+    /// A rational exponent, given by `diman::Ratio`.
+    Ratio(Box<syn::Type>),
+}
+
+/// Whether the path names `diman::Ratio`, either as `Ratio`,
+/// `diman::Ratio` or `::diman::Ratio`, without generic arguments.
+fn is_diman_ratio(path: &syn::Path) -> bool {
+    let segments: Vec<_> = path.segments.iter().collect();
+    let names: Vec<_> = segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect();
+    let no_arguments = segments.iter().all(|segment| segment.arguments.is_none());
+    let names_ratio = match names.as_slice() {
+        [ratio] => ratio == "Ratio" && path.leading_colon.is_none(),
+        [diman, ratio] => diman == "diman" && ratio == "Ratio",
+        _ => false,
+    };
+    no_arguments && names_ratio
+}
+
+fn get_field_type(ty: &syn::Type) -> syn::Result<FieldType> {
+    if let syn::Type::Path(ref type_path) = ty {
+        if type_path.qself.is_none() {
+            let path = &type_path.path;
+            if is_diman_ratio(path) {
+                return Ok(FieldType::Ratio(Box::new(ty.clone())));
+            }
+            if let Some(ident) = path.get_ident() {
+                if ALLOWED_TYPES.contains(&ident.to_string().as_str()) {
+                    return Ok(FieldType::Int(ident.clone()));
                 }
-                field_names.push(f.ident.as_ref().unwrap().clone());
             }
         }
     }
+    Err(syn::Error::new_spanned(
+        ty,
+        "Unexpected field type, expected i8, i32, i64 or diman::Ratio.",
+    ))
+}
 
-    let none_gen: proc_macro2::TokenStream = field_names
+/// The named fields of the dimension struct, along with their types.
+fn get_fields(input: &DeriveInput) -> syn::Result<Vec<(syn::Ident, FieldType)>> {
+    let syn::Data::Struct(s) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "Dimension types need to be structs.",
+        ));
+    };
+    let syn::Fields::Named(named_fields) = &s.fields else {
+        return Err(syn::Error::new_spanned(
+            &s.fields,
+            "Dimension types need to have named fields.",
+        ));
+    };
+    let mut errors = Errors::default();
+    let fields = named_fields
+        .named
         .iter()
-        .map(|ident| {
-            quote! {
-                #ident: 0,
-            }
+        .filter_map(|f| {
+            errors
+                .add(get_field_type(&f.ty))
+                .map(|field_type| (f.ident.clone().unwrap(), field_type))
         })
         .collect();
+    errors.finish()?;
+    Ok(fields)
+}
 
-    let mul_gen: proc_macro2::TokenStream = field_names
-        .iter()
-        .map(|ident| {
+/// An integer field of a dimension struct. Arithmetic on the field
+/// is performed in `i64` and converted back to the type of the field,
+/// so that overflow is caught during const evaluation.
+struct IntField<'a> {
+    ident: &'a syn::Ident,
+    ty: &'a syn::Ident,
+}
+
+impl IntField<'_> {
+    fn is_i64(&self) -> bool {
+        self.ty == "i64"
+    }
+
+    /// Assign the value of an `i64` expression to this field.
+    fn assign(&self, value: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let Self { ident, ty } = self;
+        if self.is_i64() {
+            quote! { #ident: #value, }
+        } else {
             quote! {
-                #ident: self.#ident + other.#ident,
+                #ident: {
+                    let value = #value;
+                    if value < #ty::MIN as i64 || value > #ty::MAX as i64 {
+                        panic!("Overflow in dimension exponent.");
+                    }
+                    value as #ty
+                },
             }
-        })
-        .collect();
+        }
+    }
 
-    let div_gen: proc_macro2::TokenStream = field_names
-        .iter()
-        .map(|ident| {
+    /// Assign the value of an `Option<i64>` expression to this field,
+    /// returning `None` if it is `None` or out of range for the field.
+    fn checked_assign(&self, value: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let Self { ident, ty } = self;
+        if self.is_i64() {
             quote! {
-                #ident: self.#ident - other.#ident,
+                #ident: match #value {
+                    Some(value) => value,
+                    None => return None,
+                },
             }
-        })
-        .collect();
+        } else {
+            quote! {
+                #ident: match #value {
+                    Some(value) if value >= #ty::MIN as i64 && value <= #ty::MAX as i64 => value as #ty,
+                    _ => return None,
+                },
+            }
+        }
+    }
+}
 
-    let inv_gen: proc_macro2::TokenStream = field_names
-        .iter()
-        .map(|ident| {
+/// Allows writing `self.#field` to access the field as an `i64`.
+impl quote::ToTokens for IntField<'_> {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let ident = self.ident;
+        if self.is_i64() {
+            tokens.extend(quote! { #ident });
+        } else {
+            tokens.extend(quote! { #ident as i64 });
+        }
+    }
+}
+
+pub(crate) fn dimension_methods_impl(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let type_name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = &input.generics.split_for_impl();
+    let fields = get_fields(input)?;
+    let gen_for_fields =
+        |int: &dyn Fn(&IntField) -> proc_macro2::TokenStream,
+         ratio: &dyn Fn(&syn::Ident, &syn::Type) -> proc_macro2::TokenStream| {
+            fields
+                .iter()
+                .map(|(ident, field_type)| match field_type {
+                    FieldType::Int(ty) => int(&IntField { ident, ty }),
+                    FieldType::Ratio(ty) => ratio(ident, ty),
+                })
+                .collect::<proc_macro2::TokenStream>()
+        };
+
+    let none_gen = gen_for_fields(
+        &|field| {
+            let ident = field.ident;
+            quote! { #ident: 0, }
+        },
+        &|ident, ty| quote! { #ident: <#ty>::int(0), },
+    );
+
+    let mul_gen = gen_for_fields(
+        &|field| field.assign(quote! { self.#field + other.#field }),
+        &|ident, _| quote! { #ident: self.#ident.add(other.#ident), },
+    );
+
+    let checked_mul_gen = gen_for_fields(
+        &|field| field.checked_assign(quote! { (self.#field).checked_add(other.#field) }),
+        &|ident, _| {
             quote! {
-                #ident: -self.#ident,
+                #ident: match self.#ident.checked_add(other.#ident) {
+                    Some(value) => value,
+                    None => return None,
+                },
             }
-        })
-        .collect();
+        },
+    );
 
-    let powi_gen: proc_macro2::TokenStream = field_names
-        .iter()
-        .map(|ident| {
+    let div_gen = gen_for_fields(
+        &|field| field.assign(quote! { self.#field - other.#field }),
+        &|ident, _| quote! { #ident: self.#ident.sub(other.#ident), },
+    );
+
+    let inv_gen = gen_for_fields(
+        &|field| field.assign(quote! { 0 - self.#field }),
+        &|ident, _| quote! { #ident: self.#ident.neg(), },
+    );
+
+    let powi_gen = gen_for_fields(
+        &|field| field.assign(quote! { self.#field * other as i64 }),
+        &|ident, ty| quote! { #ident: self.#ident.mul(<#ty>::int(other as i64)), },
+    );
+
+    let checked_powi_gen = gen_for_fields(
+        &|field| field.checked_assign(quote! { (self.#field).checked_mul(other as i64) }),
+        &|ident, ty| {
             quote! {
-                #ident: self.#ident * other,
+                #ident: match self.#ident.checked_mul(<#ty>::int(other as i64)) {
+                    Some(value) => value,
+                    None => return None,
+                },
             }
-        })
-        .collect();
+        },
+    );
 
-    let sqrt_safety_gen: proc_macro2::TokenStream = field_names.iter().map(|ident| {
-        quote! {
-            if self.#ident % 2 != 0 {
-                panic!("Cannot take square root of quantity with a dimension that is not divisible by 2 in all components.");
+    let sqrt_safety_gen = gen_for_fields(
+        &|field| {
+            quote! {
+                if self.#field % 2 != 0 {
+                    panic!("Cannot take square root of quantity with a dimension that is not divisible by 2 in all components.");
+                }
             }
-        }
-    }).collect();
+        },
+        &|_, _| quote! {},
+    );
 
-    let sqrt_gen: proc_macro2::TokenStream = field_names
-        .iter()
-        .map(|ident| {
+    let sqrt_gen = gen_for_fields(
+        &|field| field.assign(quote! { self.#field / 2 }),
+        &|ident, ty| quote! { #ident: self.#ident.div(<#ty>::int(2)), },
+    );
+
+    let cbrt_safety_gen = gen_for_fields(
+        &|field| {
             quote! {
-                #ident: self.#ident / 2,
+                if self.#field % 3 != 0 {
+                    panic!("Cannot take cubic root of quantity with a dimension that is not divisible by 3 in all components.");
+                }
             }
-        })
-        .collect();
+        },
+        &|_, _| quote! {},
+    );
+
+    let cbrt_gen = gen_for_fields(
+        &|field| field.assign(quote! { self.#field / 3 }),
+        &|ident, ty| quote! { #ident: self.#ident.div(<#ty>::int(3)), },
+    );
 
-    let cbrt_safety_gen: proc_macro2::TokenStream = field_names.iter().map(|ident| {
-        quote! {
-            if self.#ident % 3 != 0 {
-                panic!("Cannot take cubic root of quantity with a dimension that is not divisible by 3 in all components.");
+    let checked_root_safety_gen = gen_for_fields(
+        &|field| {
+            quote! {
+                if self.#field % n as i64 != 0 {
+                    return None;
+                }
             }
-        }
-    }).collect();
+        },
+        &|_, _| quote! {},
+    );
 
-    let cbrt_gen: proc_macro2::TokenStream = field_names
+    let checked_root_gen = gen_for_fields(
+        &|field| field.assign(quote! { self.#field / n as i64 }),
+        &|ident, ty| {
+            quote! {
+                #ident: match self.#ident.checked_div(<#ty>::int(n as i64)) {
+                    Some(value) => value,
+                    None => return None,
+                },
+            }
+        },
+    );
+
+    let powr_safety_gen = gen_for_fields(
+        &|field| {
+            quote! {
+                if (self.#field * numerator as i64) % denominator as i64 != 0 {
+                    panic!("Cannot raise quantity to a rational power which results in a non-integer dimension.");
+                }
+            }
+        },
+        &|_, _| quote! {},
+    );
+
+    let powr_gen = gen_for_fields(
+        &|field| field.assign(quote! { self.#field * numerator as i64 / denominator as i64 }),
+        &|ident, ty| {
+            quote! { #ident: self.#ident.mul(<#ty>::new(numerator as i64, denominator as i64)), }
+        },
+    );
+
+    let setters_gen: proc_macro2::TokenStream = fields
         .iter()
-        .map(|ident| {
+        .map(|(ident, field_type)| {
+            let setter_name = quote::format_ident!("with_{}", ident);
+            let (exponent_type, value) = match field_type {
+                FieldType::Int(ty) => (quote! { #ty }, quote! { exponent }),
+                FieldType::Ratio(ty) => (quote! { i64 }, quote! { <#ty>::int(exponent) }),
+            };
+            let ratio_setter_name = quote::format_ident!("with_{}_ratio", ident);
+            let getter_name = quote::format_ident!("{}_ratio", ident);
+            let (safety, ratio_value) = match field_type {
+                FieldType::Int(ty) => (
+                    quote! {
+                        if numerator % denominator != 0 {
+                            panic!("Cannot set a fractional exponent on a dimension with integer exponents.");
+                        }
+                    },
+                    IntField { ident, ty }.assign(quote! { numerator / denominator }),
+                ),
+                FieldType::Ratio(ty) => (
+                    quote! {},
+                    quote! { #ident: <#ty>::new(numerator, denominator), },
+                ),
+            };
+            let getter_value = match field_type {
+                FieldType::Int(_) => quote! { (self.#ident as i64, 1) },
+                FieldType::Ratio(_) => {
+                    quote! { (self.#ident.numerator(), self.#ident.denominator()) }
+                }
+            };
+            let doc = format!("Set the exponent of `{}` to the given integer.", ident);
+            let ratio_doc = format!("Set the exponent of `{}` to the given fraction.", ident);
+            let getter_doc = format!(
+                "The exponent of `{}` as a pair of numerator and denominator.",
+                ident
+            );
             quote! {
-                #ident: self.#ident / 3,
+                #[doc = #doc]
+                pub const fn #setter_name(self, exponent: #exponent_type) -> Self {
+                    Self {
+                        #ident: #value,
+                        ..self
+                    }
+                }
+
+                #[doc = #ratio_doc]
+                pub const fn #ratio_setter_name(self, numerator: i64, denominator: i64) -> Self {
+                    #safety
+                    Self {
+                        #ratio_value
+                        ..self
+                    }
+                }
+
+                #[doc = #getter_doc]
+                pub const fn #getter_name(&self) -> (i64, i64) {
+                    #getter_value
+                }
             }
         })
         .collect();
 
+    let exponent_along_gen = gen_for_fields(
+        &|field| {
+            let ident = field.ident;
+            quote! {
+                if base.#ident != 0 {
+                    return (self.#ident as i64, 1);
+                }
+            }
+        },
+        &|ident, _| {
+            quote! {
+                if base.#ident.numerator() != 0 {
+                    return (self.#ident.numerator(), self.#ident.denominator());
+                }
+            }
+        },
+    );
+
+    let is_none_gen = gen_for_fields(
+        &|field| {
+            let ident = field.ident;
+            quote! { && self.#ident == 0 }
+        },
+        &|ident, _| quote! { && self.#ident.numerator() == 0 },
+    );
+
     let gen = quote! {
         impl #impl_generics #type_name #type_generics #where_clause {
             pub const fn none() -> Self {
@@ -129,12 +402,24 @@ pub(crate) fn dimension_methods_impl(input: &DeriveInput) -> proc_macro::TokenSt
                 }
             }
 
+            /// Whether all exponents are zero.
+            pub const fn is_none(&self) -> bool {
+                true #is_none_gen
+            }
+
             pub const fn dimension_mul(self, other: Self) -> Self {
                 Self {
                     #mul_gen
                 }
             }
 
+            /// Like `dimension_mul`, but returns `None` if an exponent overflows.
+            pub const fn dimension_checked_mul(self, other: Self) -> Option<Self> {
+                Some(Self {
+                    #checked_mul_gen
+                })
+            }
+
             pub const fn dimension_div(self, other: Self) -> Self {
                 Self {
                     #div_gen
@@ -153,6 +438,13 @@ pub(crate) fn dimension_methods_impl(input: &DeriveInput) -> proc_macro::TokenSt
                 }
             }
 
+            /// Like `dimension_powi`, but returns `None` if an exponent overflows.
+            pub const fn dimension_checked_powi(self, other: i32) -> Option<Self> {
+                Some(Self {
+                    #checked_powi_gen
+                })
+            }
+
             pub const fn dimension_sqrt(self) -> Self {
                 #sqrt_safety_gen
                 Self {
@@ -166,7 +458,40 @@ pub(crate) fn dimension_methods_impl(input: &DeriveInput) -> proc_macro::TokenSt
                     #cbrt_gen
                 }
             }
+
+            /// Raise the dimension to the power `numerator / denominator`.
+            /// For integer exponents, this is only possible if the resulting
+            /// exponents are integers as well.
+            pub const fn dimension_powr(self, numerator: i32, denominator: i32) -> Self {
+                #powr_safety_gen
+                Self {
+                    #powr_gen
+                }
+            }
+
+            #setters_gen
+
+            /// The exponent of the first field in which `base` has a nonzero
+            /// exponent, as a pair of numerator and denominator. Used by
+            /// `unit_system` to write dimensions in terms of base units.
+            #[doc(hidden)]
+            pub const fn exponent_along(&self, base: &Self) -> (i64, i64) {
+                #exponent_along_gen
+                (0, 1)
+            }
+
+            /// Take the n-th root of the dimension, if all components are divisible by n.
+            /// Returns `None` for `n == 0`.
+            pub const fn dimension_checked_root(self, n: i32) -> Option<Self> {
+                if n == 0 {
+                    return None;
+                }
+                #checked_root_safety_gen
+                Some(Self {
+                    #checked_root_gen
+                })
+            }
         }
     };
-    gen.into()
+    Ok(gen)
 }
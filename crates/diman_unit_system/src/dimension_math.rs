@@ -1,4 +1,6 @@
-use crate::types::Dimensions;
+use syn::Ident;
+
+use crate::types::{Dimensions, Exponent, ExponentType};
 
 #[derive(Clone)]
 pub struct DimensionsAndFactor {
@@ -10,44 +12,98 @@ impl Dimensions {
     pub fn none() -> Self {
         Self { fields: vec![] }
     }
+
+    pub fn is_none(&self) -> bool {
+        self.fields.iter().all(|field| field.value.numerator() == 0)
+    }
 }
 
-impl std::ops::Mul for DimensionsAndFactor {
-    type Output = Self;
+/// Fields which are missing have an exponent of zero.
+impl PartialEq for Dimensions {
+    fn eq(&self, other: &Self) -> bool {
+        let exponent = |dimensions: &Self, ident: &Ident| {
+            dimensions
+                .fields
+                .iter()
+                .filter(|field| &field.ident == ident)
+                .try_fold(Exponent::int(0), |total, field| {
+                    total.checked_add(field.value)
+                })
+        };
+        self.fields
+            .iter()
+            .chain(other.fields.iter())
+            .all(|field| exponent(self, &field.ident) == exponent(other, &field.ident))
+    }
+}
 
-    fn mul(self, rhs: Self) -> Self::Output {
+impl Dimensions {
+    /// The factor by which a quantity of this dimension changes
+    /// if the base units are scaled by the given factors.
+    pub fn base_scale(&self, scales: &[(Ident, f64)]) -> f64 {
+        self.fields
+            .iter()
+            .filter_map(|field| {
+                let (_, scale) = scales.iter().find(|(ident, _)| ident == &field.ident)?;
+                Some(if field.value.is_int() {
+                    scale.powi(field.value.numerator() as i32)
+                } else {
+                    scale.powf(field.value.to_f64())
+                })
+            })
+            .product()
+    }
+}
+
+impl ExponentType {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "i8" => Some(Self::I8),
+            "i32" => Some(Self::I32),
+            "i64" => Some(Self::I64),
+            "Ratio" => Some(Self::Ratio),
+            _ => None,
+        }
+    }
+
+    pub fn contains(&self, value: Exponent) -> bool {
+        if !value.is_int() {
+            return matches!(self, Self::Ratio | Self::Unspecified);
+        }
+        match self {
+            Self::I8 => i8::try_from(value.numerator()).is_ok(),
+            Self::I32 => i32::try_from(value.numerator()).is_ok(),
+            Self::I64 | Self::Ratio | Self::Unspecified => true,
+        }
+    }
+}
+
+impl crate::expression::MulDiv for DimensionsAndFactor {
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
         let mut fields = self.dimensions.fields;
         for f2 in rhs.dimensions.fields {
             let same_field = fields.iter_mut().find(|f1| f1.ident == f2.ident);
             if let Some(same_field) = same_field {
-                same_field.value += f2.value;
+                same_field.value = same_field.value.checked_add(f2.value)?;
             } else {
                 fields.push(f2);
             }
         }
-        Self {
+        Some(Self {
             dimensions: Dimensions { fields },
             factor: self.factor * rhs.factor,
-        }
+        })
     }
-}
 
-impl std::ops::Div for DimensionsAndFactor {
-    type Output = Self;
-
-    // :D
-    #[allow(clippy::suspicious_arithmetic_impl)]
-    fn div(self, rhs: Self) -> Self::Output {
-        self * rhs.inv()
+    fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.checked_mul(rhs.checked_powi(-1)?)
     }
-}
 
-impl DimensionsAndFactor {
-    fn inv(mut self) -> Self {
+    fn checked_powi(mut self, exponent: i32) -> Option<Self> {
         for field in self.dimensions.fields.iter_mut() {
-            field.value = -field.value;
+            field.value = field.value.checked_mul(Exponent::int(exponent as i64))?;
         }
-        self.factor = 1.0 / self.factor;
-        self
+        self.factor = self.factor.powi(exponent);
+        Some(self)
     }
 }
@@ -11,16 +11,16 @@ pub enum Expr<T> {
 pub enum Factor<T> {
     Value(T),
     ParenExpr(Box<Expr<T>>),
+    /// A factor raised to an integer power, as in `Length^2`.
+    Power(Box<Factor<T>>, i32),
 }
 
-pub trait MulDiv:
-    std::ops::Mul<Output = Self> + std::ops::Div<Output = Self> + Sized + Clone
-{
-}
-
-impl<T> MulDiv for T where
-    T: std::ops::Mul<Output = Self> + std::ops::Div<Output = Self> + Sized + Clone
-{
+/// The operations needed to evaluate an expression, which
+/// return `None` on overflow.
+pub trait MulDiv: Sized + Clone {
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+    fn checked_powi(self, exponent: i32) -> Option<Self>;
 }
 
 impl<T> Expr<T> {
@@ -52,31 +52,35 @@ impl<T> Factor<T> {
         match self {
             Factor::Value(val) => Factor::Value(f(val)),
             Factor::ParenExpr(expr) => Factor::ParenExpr(Box::new(expr.map(f))),
+            Factor::Power(factor, exponent) => Factor::Power(Box::new(factor.map(f)), exponent),
         }
     }
     pub fn iter_vals<'a>(&'a self) -> Box<dyn Iterator<Item = &'a T> + 'a> {
         match self {
             Factor::Value(val) => Box::new(std::iter::once(val)),
             Factor::ParenExpr(expr) => expr.iter_vals(),
+            Factor::Power(factor, _) => factor.iter_vals(),
         }
     }
 }
 
 impl<T: MulDiv> Expr<T> {
-    pub fn eval(&self) -> T {
+    /// Evaluate the expression, returning `None` on overflow.
+    pub fn eval(&self) -> Option<T> {
         match self {
             Expr::Value(val) => val.eval(),
-            Expr::Times(val, expr) => val.eval() * expr.eval(),
-            Expr::Over(val, expr) => val.eval() / expr.eval(),
+            Expr::Times(val, expr) => val.eval()?.checked_mul(expr.eval()?),
+            Expr::Over(val, expr) => val.eval()?.checked_div(expr.eval()?),
         }
     }
 }
 
 impl<T: MulDiv> Factor<T> {
-    pub fn eval(&self) -> T {
+    pub fn eval(&self) -> Option<T> {
         match self {
-            Factor::Value(val) => val.clone(),
+            Factor::Value(val) => Some(val.clone()),
             Factor::ParenExpr(expr) => expr.eval(),
+            Factor::Power(factor, exponent) => factor.eval()?.checked_powi(*exponent),
         }
     }
 }
@@ -88,29 +92,45 @@ mod tests {
 
     use super::super::parse::expression::tests::parse_expr;
 
-    impl std::ops::Mul for MyInt {
-        type Output = MyInt;
-
-        fn mul(self, rhs: Self) -> Self::Output {
-            Self(self.0 * rhs.0)
+    impl super::MulDiv for MyInt {
+        fn checked_mul(self, rhs: Self) -> Option<Self> {
+            Some(Self(self.0.checked_mul(rhs.0)?))
         }
-    }
 
-    impl std::ops::Div for MyInt {
-        type Output = MyInt;
+        fn checked_div(self, rhs: Self) -> Option<Self> {
+            Some(Self(self.0.checked_div(rhs.0)?))
+        }
 
-        fn div(self, rhs: Self) -> Self::Output {
-            Self(self.0 / rhs.0)
+        fn checked_powi(self, exponent: i32) -> Option<Self> {
+            Some(Self(self.0.checked_pow(exponent as u32)?))
         }
     }
 
     #[test]
     fn mul_expr() {
-        assert_eq!(parse_expr(quote! { 1 * 2 * 3 * 4 / 2 }).eval(), MyInt(12));
-        assert_eq!(parse_expr(quote! { 1 * 3 * 4 / 2 }).eval(), MyInt(6));
+        assert_eq!(
+            parse_expr(quote! { 1 * 2 * 3 * 4 / 2 }).eval(),
+            Some(MyInt(12))
+        );
+        assert_eq!(parse_expr(quote! { 1 * 3 * 4 / 2 }).eval(), Some(MyInt(6)));
+        assert_eq!(parse_expr(quote! { (12 / 6) * 3 }).eval(), Some(MyInt(6)));
+        assert_eq!(parse_expr(quote! { 12 / (6 * 3) }).eval(), Some(MyInt(0)));
         assert_eq!(
             parse_expr(quote! { (1 * 3 * 4) / (2 * 3) }).eval(),
-            MyInt(2)
+            Some(MyInt(2))
         );
     }
+
+    #[test]
+    fn power_expr() {
+        assert_eq!(parse_expr(quote! { 2^3 }).eval(), Some(MyInt(8)));
+        assert_eq!(parse_expr(quote! { 3 * 2^2 }).eval(), Some(MyInt(12)));
+        assert_eq!(parse_expr(quote! { (1 * 3)^2 / 3 }).eval(), Some(MyInt(3)));
+    }
+
+    #[test]
+    fn overflowing_expr() {
+        assert_eq!(parse_expr(quote! { 2^100 }).eval(), None);
+        assert_eq!(parse_expr(quote! { 3 * 2^100 / 2 }).eval(), None);
+    }
 }
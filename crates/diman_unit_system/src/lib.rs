@@ -1,15 +1,17 @@
-#![feature(proc_macro_diagnostic)]
-
 mod codegen;
+mod conversion;
 mod derive_dimension;
 mod dimension_math;
 mod expression;
+mod logarithms;
 mod parse;
+mod prefixes;
 mod resolve;
 mod storage_types;
 mod types;
 mod verify;
 
+use conversion::unit_system_conversion_impl;
 use derive_dimension::dimension_impl;
 use syn::*;
 use verify::Verify;
@@ -38,8 +40,7 @@ use verify::Verify;
 ///         def Length = { length: 1 },
 ///         def Time = { time: 1 },
 ///         def Velocity = Length / Time,
-///         unit (meters, "m") = Length,
-///         unit (kilometers, "km") = 1000.0 * meters,
+///         unit (meters, "m", [kilo, milli]) = Length,
 ///         unit (seconds, "s") = 1.0 * Time,
 ///         unit hours = 3600 * seconds,
 ///         unit meters_per_second = meters / seconds,
@@ -51,16 +52,41 @@ use verify::Verify;
 #[proc_macro]
 pub fn unit_system(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let defs = parse_macro_input!(item as parse::types::Defs);
-    let defs: types::UnresolvedDefs = defs.verify().unwrap();
-    let resolved: types::Defs = defs.resolve().unwrap_or_else(|e| {
-        e.emit();
-        panic!("Unresolvable definitions, see other errors.")
-    });
-    resolved.code_gen().into()
+    match defs.verify().and_then(|defs| defs.resolve()) {
+        Ok(resolved) => resolved.code_gen().into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// Implement `From` to convert the quantities of one system of units
+/// into those of another one. The base dimensions of the source system
+/// are mapped onto those of the target system, optionally with the
+/// value of the source base unit in terms of the target base unit.
+/// Converting a quantity whose dimension contains a base dimension
+/// without a mapping fails to compile.
+/// Usage:
+/// ```rust ignore
+/// unit_system_conversion!(
+///     si::Quantity<si::Dimension>,
+///     cgs::Quantity<cgs::Dimension>,
+///     {
+///         length => 100 * length,
+///         mass => 1000 * mass,
+///         time => time,
+///     }
+/// );
+/// ```
+/// Due to the orphan rules, this needs to be used in the crate that
+/// defines at least one of the two quantity types.
+#[proc_macro]
+pub fn unit_system_conversion(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    unit_system_conversion_impl(item)
 }
 
 /// Derives all required methods for a dimension type.
-/// Only works on structs on which every field is `i32`.
+/// Only works on structs on which every field is `i8`, `i32`, `i64` or `diman::Ratio`.
+/// Fields of type `Ratio` allow for fractional exponents, so that
+/// taking the square root of a quantity always succeeds.
 /// Also adds derives of `PartialEq`, `Eq`, `Clone` and `Debug`.
 #[proc_macro_attribute]
 pub fn dimension(
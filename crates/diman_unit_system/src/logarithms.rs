@@ -0,0 +1,34 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::types::LogBase;
+
+impl LogBase {
+    /// The logarithm functions which can be used in the
+    /// definition of a logarithmic unit.
+    pub fn from_function_name(name: &str) -> Option<Self> {
+        match name {
+            "log10" => Some(Self::Ten),
+            "log2" => Some(Self::Two),
+            "ln" => Some(Self::E),
+            _ => None,
+        }
+    }
+
+    pub fn function_name(&self) -> &'static str {
+        match self {
+            Self::Ten => "log10",
+            Self::Two => "log2",
+            Self::E => "ln",
+        }
+    }
+
+    /// The base of the logarithm as an `f64` expression.
+    pub fn base(&self) -> TokenStream {
+        match self {
+            Self::Ten => quote! { 10.0f64 },
+            Self::Two => quote! { 2.0f64 },
+            Self::E => quote! { std::f64::consts::E },
+        }
+    }
+}
@@ -9,34 +9,69 @@ use crate::expression::Factor;
 
 impl<T: Parse> Parse for Factor<T> {
     fn parse(input: ParseStream) -> Result<Self> {
-        let lookahead = input.lookahead1();
-        if lookahead.peek(Paren) {
+        // Values can be parenthesized themselves, such as
+        // the sums of numbers in unit expressions.
+        let factor = if input.peek(Paren) && input.fork().parse::<T>().is_err() {
             let content;
             let _: token::Paren = parenthesized!(content in input);
-            Ok(Self::ParenExpr(Box::new(content.parse()?)))
+            Self::ParenExpr(Box::new(content.parse()?))
         } else {
-            Ok(Self::Value(input.parse()?))
+            Self::Value(input.parse()?)
+        };
+        if input.peek(Token![^]) {
+            let _: Token![^] = input.parse()?;
+            let sign = if input.peek(Token![-]) {
+                let _: Token![-] = input.parse()?;
+                -1
+            } else {
+                1
+            };
+            let exponent: LitInt = input.parse()?;
+            let exponent: i32 = exponent.base10_parse()?;
+            Ok(Self::Power(Box::new(factor), sign * exponent))
+        } else {
+            Ok(factor)
         }
     }
 }
 
 impl<T: Parse> Parse for Expr<T> {
     fn parse(input: ParseStream) -> Result<Self> {
-        let first_factor: Factor<T> = input.parse()?;
-        let lookahead = input.lookahead1();
-        if input.is_empty() || lookahead.peek(Token![,]) {
-            Ok(Self::Value(first_factor))
-        } else if lookahead.peek(Token![*]) {
-            let _: Token![*] = input.parse().unwrap();
-            let second_expr: Expr<T> = input.parse()?;
-            Ok(Self::Times(first_factor, Box::new(second_expr)))
-        } else if lookahead.peek(Token![/]) {
-            let _: Token![/] = input.parse().unwrap();
-            let second_expr: Expr<T> = input.parse()?;
-            Ok(Self::Over(first_factor, Box::new(second_expr)))
+        parse_expr(input, false)
+    }
+}
+
+/// The expressions are nested to the right, so that `a * b / c` is
+/// represented as `a * (b / c)`. Since this would turn `a / b * c` into
+/// `a / (b * c)`, which is easily misread, a division cannot be followed
+/// by another operator unless its right hand side is parenthesized.
+fn parse_expr<T: Parse>(input: ParseStream, after_division: bool) -> Result<Expr<T>> {
+    let first_factor: Factor<T> = input.parse()?;
+    let lookahead = input.lookahead1();
+    if input.is_empty()
+        || lookahead.peek(Token![,])
+        || lookahead.peek(Token![+])
+        || lookahead.peek(Token![-])
+    {
+        Ok(Expr::Value(first_factor))
+    } else if lookahead.peek(Token![*]) || lookahead.peek(Token![/]) {
+        if after_division {
+            return Err(Error::new(
+                input.span(),
+                "Ambiguous operator after a division.\nhelp: Use parentheses, such as `a / (b * c)` or `(a / b) * c`.",
+            ));
+        }
+        if input.peek(Token![*]) {
+            let _: Token![*] = input.parse()?;
+            let second_expr = parse_expr(input, false)?;
+            Ok(Expr::Times(first_factor, Box::new(second_expr)))
         } else {
-            Err(lookahead.error())
+            let _: Token![/] = input.parse()?;
+            let second_expr = parse_expr(input, true)?;
+            Ok(Expr::Over(first_factor, Box::new(second_expr)))
         }
+    } else {
+        Err(lookahead.error())
     }
 }
 
@@ -113,4 +148,14 @@ pub mod tests {
             )
         );
     }
+
+    #[test]
+    fn operators_after_division_are_rejected() {
+        let parse = |input| syn::parse2::<Expr<MyInt>>(input);
+        assert!(parse(quote! { 1 / 2 * 3 }).is_err());
+        assert!(parse(quote! { 1 / 2 / 3 }).is_err());
+        assert!(parse(quote! { 1 * 2 / 3 }).is_ok());
+        assert!(parse(quote! { (1 / 2) * 3 }).is_ok());
+        assert!(parse(quote! { 1 / (2 / 3) }).is_ok());
+    }
 }
@@ -8,9 +8,12 @@ use syn::{
     *,
 };
 
+use crate::verify::Errors;
+
 use self::types::{
-    ConstantEntry, Defs, DimensionEntry, DimensionInt, Dimensions, Entry, Factor, Prefix, Prefixes,
-    QuantityDefinition, QuantityEntry, Symbol, UnitEntry, UnitExpression, UnitFactor,
+    BaseEntry, ConstantEntry, Defs, DimensionEntry, DimensionInt, Dimensions, Entry, Exponent,
+    ExponentType, Factor, Logarithm, Name, Prefix, Prefixes, QuantityDefinition, QuantityEntry,
+    Sign, SignedFactor, StorageTypeEntry, Symbol, UnitEntry, UnitExpression, UnitFactor, UnitKind,
 };
 
 impl Parse for Symbol {
@@ -25,6 +28,23 @@ impl Parse for Factor {
     }
 }
 
+impl Parse for SignedFactor {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let lookahead = input.lookahead1();
+        let sign = if lookahead.peek(Token![+]) {
+            let _: Token![+] = input.parse()?;
+            Sign::Plus
+        } else if lookahead.peek(Token![-]) {
+            let _: Token![-] = input.parse()?;
+            Sign::Minus
+        } else {
+            return Err(lookahead.error());
+        };
+        let value = input.parse()?;
+        Ok(Self { sign, value })
+    }
+}
+
 impl Parse for DimensionInt {
     fn parse(input: ParseStream) -> Result<Self> {
         Ok(Self(input.parse()?))
@@ -56,18 +76,54 @@ impl Parse for DimensionEntry {
     fn parse(input: ParseStream) -> Result<Self> {
         let ident: Ident = input.parse()?;
         let _: Token![:] = input.parse()?;
-        let value: DimensionInt = input.parse()?;
+        let value: Exponent = input.parse()?;
         Ok(Self { ident, value })
     }
 }
 
+impl Parse for Exponent {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let numerator = input.parse()?;
+        let denominator = if input.peek(Token![/]) {
+            let _: Token![/] = input.parse()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        Ok(Self {
+            numerator,
+            denominator,
+        })
+    }
+}
+
+impl Parse for Name {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(Self(input.parse()?))
+    }
+}
+
 impl Parse for UnitFactor {
     fn parse(input: ParseStream) -> Result<Self> {
         let lookahead = input.lookahead1();
         if lookahead.peek(Ident) {
-            Ok(Self::UnitOrQuantity(input.parse()?))
+            let ident: Ident = input.parse()?;
+            if ident == "pi" || ident == "e" {
+                Ok(Self::Constant(ident))
+            } else {
+                Ok(Self::UnitOrQuantity(ident))
+            }
         } else if lookahead.peek(Lit) {
             Ok(Self::Number(input.parse()?))
+        } else if lookahead.peek(Paren) {
+            let content;
+            let _: token::Paren = parenthesized! { content in input };
+            let first = content.parse()?;
+            let mut terms = vec![];
+            while !content.is_empty() {
+                terms.push(content.parse()?);
+            }
+            Ok(Self::Sum(first, terms))
         } else {
             Err(lookahead.error())
         }
@@ -110,17 +166,67 @@ impl Parse for UnitEntry {
         } else {
             return Err(lookahead.error());
         }
+        let dimension = parse_declared_dimension(input)?;
         let _: Token![=] = input.parse()?;
-        let rhs: UnitExpression = input.parse()?;
+        let (rhs, kind) = if input.fork().call(parse_logarithm).is_ok() {
+            let (logarithm, rhs) = parse_logarithm(input)?;
+            (rhs, UnitKind::Logarithmic(logarithm))
+        } else {
+            let rhs: UnitExpression = input.parse()?;
+            if input.peek(Token![+]) || input.peek(Token![-]) {
+                (rhs, UnitKind::Affine(input.parse()?))
+            } else {
+                (rhs, UnitKind::Linear)
+            }
+        };
         Ok(Self {
             name,
             symbol,
             prefixes,
+            dimension,
             rhs,
+            kind,
         })
     }
 }
 
+/// Parse the optional quantity on the left hand side of a unit
+/// or constant, as in `unit newtons: Force = ...`.
+fn parse_declared_dimension(input: ParseStream) -> Result<Option<Ident>> {
+    if input.peek(Token![:]) {
+        let _: Token![:] = input.parse()?;
+        Ok(Some(input.parse()?))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Parse the definition of a logarithmic unit, such as
+/// `10 * log10(milliwatts)`, into the logarithm and its argument.
+fn parse_logarithm(input: ParseStream) -> Result<(Logarithm, UnitExpression)> {
+    let scale = if input.peek(Ident) {
+        None
+    } else {
+        let sign = if input.peek(Token![-]) {
+            let _: Token![-] = input.parse()?;
+            Sign::Minus
+        } else {
+            Sign::Plus
+        };
+        let value = input.parse()?;
+        let _: Token![*] = input.parse()?;
+        Some(SignedFactor { sign, value })
+    };
+    let function = input.parse()?;
+    let content;
+    let _: token::Paren = parenthesized! { content in input };
+    let rhs = content.parse()?;
+    if !content.is_empty() {
+        return Err(content.error("Unexpected tokens in logarithm"));
+    }
+    Ok((Logarithm { scale, function }, rhs))
+}
+
 impl Parse for Dimensions {
     fn parse(input: ParseStream) -> Result<Self> {
         let content;
@@ -145,9 +251,32 @@ impl Parse for QuantityEntry {
 impl Parse for ConstantEntry {
     fn parse(input: ParseStream) -> Result<Self> {
         let name = input.parse()?;
+        let dimension = parse_declared_dimension(input)?;
         let _: Token![=] = input.parse()?;
         let rhs: UnitExpression = input.parse()?;
-        Ok(Self { name, rhs })
+        Ok(Self {
+            name,
+            dimension,
+            rhs,
+        })
+    }
+}
+
+impl Parse for BaseEntry {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let dimension = input.parse()?;
+        let _: Token![=] = input.parse()?;
+        let unit = input.parse()?;
+        Ok(Self { dimension, unit })
+    }
+}
+
+impl Parse for StorageTypeEntry {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let module_name = input.parse()?;
+        let _: Token![=] = input.parse()?;
+        let type_ = input.parse()?;
+        Ok(Self { module_name, type_ })
     }
 }
 
@@ -158,10 +287,12 @@ impl Parse for Entry {
             "def" => Ok(Self::Quantity(input.parse()?)),
             "unit" => Ok(Self::Unit(input.parse()?)),
             "constant" => Ok(Self::Constant(input.parse()?)),
+            "base" => Ok(Self::Base(input.parse()?)),
+            "storage" => Ok(Self::StorageType(input.parse()?)),
             ident => Err(Error::new(
                 keyword.span(),
                 format!(
-                    "Unexpected identifier: {}, expected \"def\", \"unit\" or \"constant\"",
+                    "Unexpected identifier: {}, expected \"def\", \"unit\", \"constant\", \"base\" or \"storage\"",
                     ident
                 ),
             )),
@@ -174,28 +305,65 @@ impl Parse for Defs {
         let quantity_type: Type = input.parse()?;
         let _: Token![,] = input.parse()?;
         let dimension_type: Type = input.parse()?;
+        let exponent_type = if input.peek(Token![:]) {
+            let _: Token![:] = input.parse()?;
+            ExponentType(Some(input.parse()?))
+        } else {
+            ExponentType(None)
+        };
         let _: Token![,] = input.parse()?;
         let content;
         let _: token::Bracket = bracketed!(content in input);
         let mut quantities = vec![];
         let mut units = vec![];
         let mut constants = vec![];
-        for item in content
-            .parse_terminated::<_, Token![,]>(Entry::parse)?
-            .into_iter()
-        {
-            match item {
-                Entry::Quantity(q) => quantities.push(q),
-                Entry::Unit(u) => units.push(u),
-                Entry::Constant(c) => constants.push(c),
+        let mut bases = vec![];
+        let mut storage_types = vec![];
+        let mut errors = Errors::default();
+        while !content.is_empty() {
+            match errors.add(content.parse::<Entry>()) {
+                Some(Entry::Quantity(q)) => quantities.push(q),
+                Some(Entry::Unit(u)) => units.push(u),
+                Some(Entry::Constant(c)) => constants.push(c),
+                Some(Entry::Base(b)) => bases.push(b),
+                Some(Entry::StorageType(s)) => storage_types.push(s),
+                None => skip_to_next_entry(&content)?,
+            }
+            if content.is_empty() {
+                break;
+            }
+            if errors.add(content.parse::<Token![,]>()).is_none() {
+                skip_to_next_entry(&content)?;
+                if !content.is_empty() {
+                    let _: Token![,] = content.parse()?;
+                }
             }
         }
+        errors.finish()?;
         Ok(Self {
             dimension_type,
+            exponent_type,
             quantity_type,
             quantities,
             units,
             constants,
+            bases,
+            storage_types,
         })
     }
 }
+
+/// Skip the tokens of an entry which failed to parse, so that
+/// parsing can resume at the next entry and report its errors as well.
+fn skip_to_next_entry(input: ParseStream) -> Result<()> {
+    input.step(|cursor| {
+        let mut rest = *cursor;
+        while let Some((token, next)) = rest.token_tree() {
+            if matches!(&token, proc_macro2::TokenTree::Punct(punct) if punct.as_char() == ',') {
+                break;
+            }
+            rest = next;
+        }
+        Ok(((), rest))
+    })
+}
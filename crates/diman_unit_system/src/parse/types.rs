@@ -12,15 +12,47 @@ pub struct Prefixes(pub Punctuated<Prefix, Token![,]>);
 
 pub struct DimensionInt(pub Lit);
 
+/// An integer or a fraction such as `3/2`.
+pub struct Exponent {
+    pub numerator: DimensionInt,
+    pub denominator: Option<DimensionInt>,
+}
+
 pub struct Factor(pub Lit);
 
+/// The name of a quantity, unit or constant.
+pub struct Name(pub Ident);
+
 pub struct Symbol(pub Lit);
 
+pub struct ExponentType(pub Option<Ident>);
+
+pub enum Sign {
+    Plus,
+    Minus,
+}
+
+pub struct SignedFactor {
+    pub sign: Sign,
+    pub value: Factor,
+}
+
+pub struct Logarithm {
+    pub scale: Option<SignedFactor>,
+    pub function: Ident,
+}
+
+pub enum UnitKind {
+    Linear,
+    Affine(SignedFactor),
+    Logarithmic(Logarithm),
+}
+
 #[derive(Verify)]
 #[verified(crate::types::DimensionEntry)]
 pub struct DimensionEntry {
     pub ident: Ident,
-    pub value: DimensionInt,
+    pub value: Exponent,
 }
 
 #[derive(Verify)]
@@ -31,11 +63,13 @@ pub struct Dimensions {
 
 pub type QuantityExpression = Expr<Ident>;
 
-#[derive(Verify)]
-#[verified(crate::types::UnitFactor)]
 pub enum UnitFactor {
     UnitOrQuantity(Ident),
     Number(Factor),
+    /// One of the built-in constants `pi` and `e`.
+    Constant(Ident),
+    /// A sum of numbers in parentheses, such as `(1 + 1e-9)`.
+    Sum(Factor, Vec<SignedFactor>),
 }
 
 pub type UnitExpression = Expr<UnitFactor>;
@@ -50,38 +84,60 @@ pub enum QuantityDefinition {
 #[derive(Verify)]
 #[verified(crate::types::UnitEntry)]
 pub struct UnitEntry {
-    pub name: Ident,
+    pub name: Name,
     pub symbol: Option<Symbol>,
     pub prefixes: Prefixes,
+    pub dimension: Option<Ident>,
     pub rhs: UnitExpression,
+    pub kind: UnitKind,
 }
 
 #[derive(Verify)]
 #[verified(crate::types::QuantityEntry)]
 pub struct QuantityEntry {
-    pub name: Ident,
+    pub name: Name,
     pub rhs: QuantityDefinition,
 }
 
 #[derive(Verify)]
 #[verified(crate::types::ConstantEntry)]
 pub struct ConstantEntry {
-    pub name: Ident,
+    pub name: Name,
+    pub dimension: Option<Ident>,
     pub rhs: UnitExpression,
 }
 
+#[derive(Verify)]
+#[verified(crate::types::BaseEntry)]
+pub struct BaseEntry {
+    pub dimension: Ident,
+    pub unit: Ident,
+}
+
+#[derive(Verify)]
+#[verified(crate::types::StorageTypeEntry)]
+pub struct StorageTypeEntry {
+    pub module_name: Ident,
+    pub type_: Type,
+}
+
 pub enum Entry {
     Quantity(QuantityEntry),
     Unit(UnitEntry),
     Constant(ConstantEntry),
+    Base(BaseEntry),
+    StorageType(StorageTypeEntry),
 }
 
 #[derive(Verify)]
 #[verified(crate::types::UnresolvedDefs)]
 pub struct Defs {
     pub dimension_type: Type,
+    pub exponent_type: ExponentType,
     pub quantity_type: Type,
     pub quantities: Vec<QuantityEntry>,
     pub units: Vec<UnitEntry>,
     pub constants: Vec<ConstantEntry>,
+    pub bases: Vec<BaseEntry>,
+    pub storage_types: Vec<StorageTypeEntry>,
 }
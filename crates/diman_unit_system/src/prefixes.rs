@@ -0,0 +1,246 @@
+use syn::Ident;
+
+use crate::{
+    expression::{Expr, Factor},
+    types::{Prefix, UnitEntry, UnitFactor, UnitKind},
+};
+
+const METRIC_PREFIXES: &[Prefix] = &[
+    Prefix {
+        name: "quecto",
+        short: "q",
+        factor: 1e-30,
+    },
+    Prefix {
+        name: "ronto",
+        short: "r",
+        factor: 1e-27,
+    },
+    Prefix {
+        name: "yocto",
+        short: "y",
+        factor: 1e-24,
+    },
+    Prefix {
+        name: "zepto",
+        short: "z",
+        factor: 1e-21,
+    },
+    Prefix {
+        name: "atto",
+        short: "a",
+        factor: 1e-18,
+    },
+    Prefix {
+        name: "femto",
+        short: "f",
+        factor: 1e-15,
+    },
+    Prefix {
+        name: "pico",
+        short: "p",
+        factor: 1e-12,
+    },
+    Prefix {
+        name: "nano",
+        short: "n",
+        factor: 1e-9,
+    },
+    Prefix {
+        name: "micro",
+        short: "µ",
+        factor: 1e-6,
+    },
+    Prefix {
+        name: "milli",
+        short: "m",
+        factor: 1e-3,
+    },
+    Prefix {
+        name: "centi",
+        short: "c",
+        factor: 1e-2,
+    },
+    Prefix {
+        name: "deci",
+        short: "d",
+        factor: 1e-1,
+    },
+    Prefix {
+        name: "deca",
+        short: "da",
+        factor: 1e1,
+    },
+    Prefix {
+        name: "hecto",
+        short: "h",
+        factor: 1e2,
+    },
+    Prefix {
+        name: "kilo",
+        short: "k",
+        factor: 1e3,
+    },
+    Prefix {
+        name: "mega",
+        short: "M",
+        factor: 1e6,
+    },
+    Prefix {
+        name: "giga",
+        short: "G",
+        factor: 1e9,
+    },
+    Prefix {
+        name: "tera",
+        short: "T",
+        factor: 1e12,
+    },
+    Prefix {
+        name: "peta",
+        short: "P",
+        factor: 1e15,
+    },
+    Prefix {
+        name: "exa",
+        short: "E",
+        factor: 1e18,
+    },
+    Prefix {
+        name: "zetta",
+        short: "Z",
+        factor: 1e21,
+    },
+    Prefix {
+        name: "yotta",
+        short: "Y",
+        factor: 1e24,
+    },
+    Prefix {
+        name: "ronna",
+        short: "R",
+        factor: 1e27,
+    },
+    Prefix {
+        name: "quetta",
+        short: "Q",
+        factor: 1e30,
+    },
+];
+
+const BINARY_PREFIXES: &[Prefix] = &[
+    Prefix {
+        name: "kibi",
+        short: "Ki",
+        factor: 1024.0,
+    },
+    Prefix {
+        name: "mebi",
+        short: "Mi",
+        factor: 1048576.0,
+    },
+    Prefix {
+        name: "gibi",
+        short: "Gi",
+        factor: 1073741824.0,
+    },
+    Prefix {
+        name: "tebi",
+        short: "Ti",
+        factor: 1099511627776.0,
+    },
+    Prefix {
+        name: "pebi",
+        short: "Pi",
+        factor: 1125899906842624.0,
+    },
+    Prefix {
+        name: "exbi",
+        short: "Ei",
+        factor: 1152921504606846976.0,
+    },
+    Prefix {
+        name: "zebi",
+        short: "Zi",
+        factor: 1180591620717411303424.0,
+    },
+    Prefix {
+        name: "yobi",
+        short: "Yi",
+        factor: 1208925819614629174706176.0,
+    },
+];
+
+/// Named groups of prefixes which can be used in place of
+/// a single prefix.
+const PREFIX_GROUPS: &[(&str, &[Prefix])] = &[
+    ("metric_prefixes", METRIC_PREFIXES),
+    ("binary_prefixes", BINARY_PREFIXES),
+];
+
+impl Prefix {
+    /// Looks up a prefix or a named group of prefixes.
+    pub fn from_name(name: &str) -> Option<Vec<Self>> {
+        if let Some((_, group)) = PREFIX_GROUPS
+            .iter()
+            .find(|(group_name, _)| *group_name == name)
+        {
+            return Some(group.to_vec());
+        }
+        METRIC_PREFIXES
+            .iter()
+            .chain(BINARY_PREFIXES.iter())
+            .find(|prefix| prefix.name == name)
+            .map(|prefix| vec![prefix.clone()])
+    }
+}
+
+impl UnitEntry {
+    /// Returns the unit itself, followed by one unit
+    /// for each of its prefixes. The prefixed units are defined
+    /// in terms of the unprefixed one, so they can be resolved
+    /// just like any other unit.
+    pub fn expand_prefixes(mut self) -> Vec<UnitEntry> {
+        let prefixes = std::mem::take(&mut self.prefixes);
+        let prefixed = prefixes.into_iter().map(|prefix| {
+            let name = Ident::new(&format!("{}{}", prefix.name, self.name), self.name.span());
+            let symbol = self
+                .symbol
+                .as_ref()
+                .map(|symbol| format!("{}{}", prefix.short, symbol));
+            let (rhs, kind) = match self.kind {
+                // A prefixed logarithmic unit shares the reference of
+                // the unprefixed unit, but counts in different steps,
+                // for example a bel is ten decibels.
+                UnitKind::Logarithmic { base, scale } => (
+                    self.rhs.clone(),
+                    UnitKind::Logarithmic {
+                        base,
+                        scale: scale / prefix.factor,
+                    },
+                ),
+                _ => (
+                    Expr::Times(
+                        Factor::Value(UnitFactor::Number(prefix.factor)),
+                        Box::new(Expr::Value(Factor::Value(UnitFactor::UnitOrQuantity(
+                            self.name.clone(),
+                        )))),
+                    ),
+                    UnitKind::Linear,
+                ),
+            };
+            UnitEntry {
+                name,
+                symbol,
+                prefixes: vec![],
+                // A prefixed unit has the dimension of the unprefixed unit,
+                // so the declared quantity is only checked for the latter.
+                dimension: None,
+                rhs,
+                kind,
+            }
+        });
+        let prefixed: Vec<_> = prefixed.collect();
+        std::iter::once(self).chain(prefixed).collect()
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::{HashMap, HashSet};
+
+use syn::Ident;
+
+use crate::{
+    types::{ConstantEntry, Dimensions, Exponent, QuantityEntry, UnitEntry, UnitKind},
+    verify::Errors,
+};
+
+use super::{error::Error, item::ResolvedItem};
+
+/// Check that the units and constants whose quantity is given on the
+/// left hand side, as in `unit newtons: Force = ...`, have the dimension
+/// of this quantity.
+pub fn check_declared_dimensions(
+    quantities: &[QuantityEntry],
+    units: &[UnitEntry],
+    constants: &[ConstantEntry],
+    items: &HashMap<Ident, ResolvedItem>,
+) -> syn::Result<()> {
+    let quantity_names: HashSet<&Ident> = quantities.iter().map(|q| &q.name).collect();
+    let declared = units
+        .iter()
+        .filter_map(|unit| Some((&unit.name, unit.dimension.as_ref()?)))
+        .chain(
+            constants
+                .iter()
+                .filter_map(|constant| Some((&constant.name, constant.dimension.as_ref()?))),
+        );
+    let symbols = base_unit_symbols(units, items);
+    let mut errors = Errors::default();
+    for (name, quantity) in declared {
+        if !quantity_names.contains(quantity) {
+            errors.push(Error::unknown_quantity(vec![quantity.clone()]));
+            continue;
+        }
+        let expected = &items[quantity].val.dimensions;
+        let found = &items[name].val.dimensions;
+        if expected != found {
+            errors.push(Error::dimension_mismatch(
+                format_dimensions(expected, &symbols),
+                format_dimensions(found, &symbols),
+                vec![quantity.clone()],
+            ));
+        }
+    }
+    errors.finish()
+}
+
+/// The symbols of the units with a factor of one whose dimension
+/// is a single base dimension, for each base dimension.
+fn base_unit_symbols(
+    units: &[UnitEntry],
+    items: &HashMap<Ident, ResolvedItem>,
+) -> HashMap<Ident, String> {
+    let mut symbols = HashMap::new();
+    for unit in units.iter() {
+        let (Some(symbol), UnitKind::Linear) = (&unit.symbol, unit.kind) else {
+            continue;
+        };
+        let val = &items[&unit.name].val;
+        let fields: Vec<_> = val
+            .dimensions
+            .fields
+            .iter()
+            .filter(|field| field.value != Exponent::int(0))
+            .collect();
+        if let [field] = fields[..] {
+            if field.value == Exponent::int(1) && val.factor == 1.0 {
+                symbols
+                    .entry(field.ident.clone())
+                    .or_insert_with(|| symbol.clone());
+            }
+        }
+    }
+    symbols
+}
+
+/// Writes the dimension as a product of powers of base units, such as
+/// `kg m s^-2`. Base dimensions without a base unit are written by name.
+fn format_dimensions(dimensions: &Dimensions, symbols: &HashMap<Ident, String>) -> String {
+    let components: Vec<_> = dimensions
+        .fields
+        .iter()
+        .filter(|field| field.value != Exponent::int(0))
+        .map(|field| {
+            let symbol = symbols
+                .get(&field.ident)
+                .cloned()
+                .unwrap_or_else(|| field.ident.to_string());
+            if field.value == Exponent::int(1) {
+                symbol
+            } else {
+                format!("{}^{}", symbol, field.value)
+            }
+        })
+        .collect();
+    if components.is_empty() {
+        "dimensionless".to_string()
+    } else {
+        components.join(" ")
+    }
+}
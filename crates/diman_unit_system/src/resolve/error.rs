@@ -8,8 +8,34 @@ pub struct Error {
 pub type Result<T> = std::result::Result<T, Error>;
 
 pub enum ErrorKind {
-    Unresolvable,
+    /// A cycle of definitions, given by the names along the cycle.
+    /// The identifiers of the error are the references which form
+    /// the edges of the cycle. The error is reported at the first
+    /// edge, with a note at each of the other edges.
+    Cycle(Vec<Ident>),
+    /// Definitions which cannot be resolved because they depend
+    /// on a cycle, given by their names. These are reported in a
+    /// single error at the first of the names, with a note at
+    /// each of the others.
+    DependsOnCycle(Vec<Ident>),
     Undefined,
+    DuplicateDefinition,
+    /// A unit symbol which is already used by another unit.
+    DuplicateSymbol(String),
+    /// The quantity given on the left hand side of a unit or constant
+    /// is not defined as a quantity.
+    UnknownQuantity,
+    /// The dimension of a unit or constant differs from the dimension
+    /// of the quantity on its left hand side.
+    DimensionMismatch {
+        expected: String,
+        found: String,
+    },
+    NonLinearUnitReference,
+    /// More than one base unit is given for the same dimension.
+    DuplicateBase,
+    ExponentOverflow,
+    InvalidBaseUnit,
 }
 
 impl Error {
@@ -20,29 +46,193 @@ impl Error {
         }
     }
 
-    pub fn unresolvable(idents: Vec<Ident>) -> Self {
+    pub fn duplicate_definition(idents: Vec<Ident>) -> Self {
         Self {
             idents,
-            kind: ErrorKind::Unresolvable,
+            kind: ErrorKind::DuplicateDefinition,
         }
     }
 
-    pub fn emit(self) {
-        let error_msg = match self.kind {
-            ErrorKind::Unresolvable => "Unresolvable definition:",
-            ErrorKind::Undefined => "Undefined identifier:",
-        };
-        let help = match self.kind {
-            ErrorKind::Unresolvable => "Possible cause: recursive definitions?",
+    pub fn duplicate_symbol(symbol: String, idents: Vec<Ident>) -> Self {
+        Self {
+            idents,
+            kind: ErrorKind::DuplicateSymbol(symbol),
+        }
+    }
+
+    pub fn unknown_quantity(idents: Vec<Ident>) -> Self {
+        Self {
+            idents,
+            kind: ErrorKind::UnknownQuantity,
+        }
+    }
+
+    pub fn dimension_mismatch(expected: String, found: String, idents: Vec<Ident>) -> Self {
+        Self {
+            idents,
+            kind: ErrorKind::DimensionMismatch { expected, found },
+        }
+    }
+
+    pub fn non_linear_unit_reference(idents: Vec<Ident>) -> Self {
+        Self {
+            idents,
+            kind: ErrorKind::NonLinearUnitReference,
+        }
+    }
+
+    pub fn duplicate_base(idents: Vec<Ident>) -> Self {
+        Self {
+            idents,
+            kind: ErrorKind::DuplicateBase,
+        }
+    }
+
+    pub fn exponent_overflow(idents: Vec<Ident>) -> Self {
+        Self {
+            idents,
+            kind: ErrorKind::ExponentOverflow,
+        }
+    }
+
+    pub fn invalid_base_unit(idents: Vec<Ident>) -> Self {
+        Self {
+            idents,
+            kind: ErrorKind::InvalidBaseUnit,
+        }
+    }
+
+    pub fn cycle(path: Vec<Ident>, references: Vec<Ident>) -> Self {
+        Self {
+            idents: references,
+            kind: ErrorKind::Cycle(path),
+        }
+    }
+
+    pub fn depends_on_cycle(names: Vec<Ident>) -> Self {
+        Self {
+            idents: names.clone(),
+            kind: ErrorKind::DependsOnCycle(names),
+        }
+    }
+
+    /// For the errors which are reported at the first identifier,
+    /// the message at each of the other identifiers.
+    fn note(&self, ident: &Ident) -> Option<String> {
+        match &self.kind {
+            ErrorKind::Cycle(path) => {
+                let path: Vec<_> = path.iter().map(|name| name.to_string()).collect();
+                Some(format!(
+                    "Reference \"{}\" is part of the recursive definition: {}",
+                    ident,
+                    path.join(" -> ")
+                ))
+            }
+            ErrorKind::DependsOnCycle(_) => Some(format!(
+                "Definition depends on a recursive definition: \"{}\"",
+                ident
+            )),
+            _ => None,
+        }
+    }
+
+    fn message(&self, ident: &Ident) -> String {
+        match &self.kind {
+            ErrorKind::Cycle(path) => {
+                let path: Vec<_> = path.iter().map(|name| name.to_string()).collect();
+                format!("Recursive definition: {}", path.join(" -> "))
+            }
+            ErrorKind::DependsOnCycle(names) => {
+                let names: Vec<_> = names.iter().map(|name| format!("\"{}\"", name)).collect();
+                if names.len() == 1 {
+                    format!("Definition depends on a recursive definition: {}", names[0])
+                } else {
+                    format!(
+                        "Definitions depend on a recursive definition: {}",
+                        names.join(", ")
+                    )
+                }
+            }
+            ErrorKind::Undefined => format!("Undefined identifier: \"{}\"", ident),
+            ErrorKind::DuplicateDefinition => format!("Duplicate definition: \"{}\"", ident),
+            ErrorKind::DuplicateSymbol(symbol) => {
+                format!("Duplicate symbol \"{}\" of unit: \"{}\"", symbol, ident)
+            }
+            ErrorKind::UnknownQuantity => format!("Unknown quantity: \"{}\"", ident),
+            ErrorKind::DimensionMismatch { .. } => {
+                format!("Definition does not have the dimension of \"{}\"", ident)
+            }
+            ErrorKind::NonLinearUnitReference => {
+                format!("Non-linear unit used in a definition: \"{}\"", ident)
+            }
+            ErrorKind::DuplicateBase => {
+                format!("Duplicate base unit for dimension: \"{}\"", ident)
+            }
+            ErrorKind::ExponentOverflow => {
+                format!("Invalid dimension exponent in definition: \"{}\"", ident)
+            }
+            ErrorKind::InvalidBaseUnit => format!("Invalid base unit: \"{}\"", ident),
+        }
+    }
+
+    fn help(&self) -> Option<String> {
+        let help = match &self.kind {
+            ErrorKind::Cycle(_) => {
+                "Definitions cannot refer to themselves, directly or via other definitions."
+            }
+            ErrorKind::DependsOnCycle(_) => return None,
             ErrorKind::Undefined => "This identifier only appears on the right hand side.",
+            ErrorKind::DuplicateDefinition => {
+                "A quantity, unit or constant with this name is already defined. This includes the units generated from prefixes."
+            }
+            ErrorKind::DuplicateSymbol(_) => {
+                "Another unit already has this symbol, so that parsing and printing quantities would be ambiguous."
+            }
+            ErrorKind::UnknownQuantity => {
+                "The quantity on the left hand side needs to be defined with def."
+            }
+            ErrorKind::DimensionMismatch { expected, found } => {
+                return Some(format!("Expected {}, found {}.", expected, found))
+            }
+            ErrorKind::NonLinearUnitReference => {
+                "Units with an offset and logarithmic units cannot be multiplied. Define this unit in terms of the base unit instead."
+            }
+            ErrorKind::DuplicateBase => {
+                "Only one base unit can be given for each dimension."
+            }
+            ErrorKind::ExponentOverflow => {
+                "The exponent does not fit into the type of the fields of the dimension type. Fractional exponents require fields of type diman::Ratio."
+            }
+            ErrorKind::InvalidBaseUnit => {
+                "The base unit of a dimension needs to be a unit without offset whose dimension is exactly this base dimension."
+            }
         };
-        for ident in self.idents.iter() {
-            ident
-                .span()
-                .unwrap()
-                .error(format!("{} \"{}\"", error_msg, ident))
-                .help(help)
-                .emit();
-        }
+        Some(help.to_string())
+    }
+}
+
+/// An error for each of the identifiers, at the span of the identifier.
+/// Errors with a note are reported in full only at the first identifier.
+impl From<Error> for syn::Error {
+    fn from(error: Error) -> Self {
+        error
+            .idents
+            .iter()
+            .enumerate()
+            .map(|(index, ident)| {
+                let message = match (index, error.note(ident), error.help()) {
+                    (1.., Some(note), _) => note,
+                    (_, _, Some(help)) => format!("{}\nhelp: {}", error.message(ident), help),
+                    (_, _, None) => error.message(ident),
+                };
+                syn::Error::new(ident.span(), message)
+            })
+            .reduce(|mut combined, error| {
+                combined.combine(error);
+                combined
+            })
+            .unwrap_or_else(|| {
+                syn::Error::new(proc_macro2::Span::call_site(), "Invalid definitions.")
+            })
     }
 }
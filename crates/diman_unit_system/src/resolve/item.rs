@@ -4,7 +4,10 @@ use syn::Ident;
 
 use crate::{dimension_math::DimensionsAndFactor, expression::Expr};
 
-use super::resolver::Resolvable;
+use super::{
+    error::{Error, Result},
+    resolver::Resolvable,
+};
 
 pub enum IdentOrFactor {
     Factor(DimensionsAndFactor),
@@ -32,21 +35,35 @@ impl Resolvable for UnresolvedItem {
         self.name.clone()
     }
 
-    fn resolve(self, others: &HashMap<Ident, ResolvedItem>) -> Self::Resolved {
+    fn resolve(self, others: &HashMap<Ident, ResolvedItem>) -> Result<Self::Resolved> {
         match self.val {
-            ValueOrExpr::Value(val) => ResolvedItem { val },
+            ValueOrExpr::Value(val) => Ok(ResolvedItem { val }),
             ValueOrExpr::Expr(expr) => {
                 let val = expr
                     .map(|val_or_expr| match val_or_expr {
                         IdentOrFactor::Factor(factor) => factor,
                         IdentOrFactor::Ident(ident) => others[&ident].val.clone(),
                     })
-                    .eval();
-                ResolvedItem { val }
+                    .eval()
+                    .ok_or_else(|| Error::exponent_overflow(vec![self.name]))?;
+                Ok(ResolvedItem { val })
             }
         }
     }
 
+    fn dependencies(&self) -> Vec<Ident> {
+        match &self.val {
+            ValueOrExpr::Value(_) => vec![],
+            ValueOrExpr::Expr(expr) => expr
+                .iter_vals()
+                .filter_map(|val| match val {
+                    IdentOrFactor::Factor(_) => None,
+                    IdentOrFactor::Ident(ident) => Some(ident.clone()),
+                })
+                .collect(),
+        }
+    }
+
     fn is_resolvable(&self, others: &HashMap<Ident, ResolvedItem>) -> bool {
         match &self.val {
             ValueOrExpr::Value(_) => true,
@@ -72,6 +72,7 @@ impl ItemConversion for UnitEntry {
             dimension: item.val.dimensions,
             factor: item.val.factor,
             symbol: self.symbol,
+            kind: self.kind,
         }
     }
 
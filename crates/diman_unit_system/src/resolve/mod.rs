@@ -1,3 +1,4 @@
+mod declared_dimension;
 mod error;
 mod item;
 mod item_conversion;
@@ -7,9 +8,14 @@ use std::collections::{HashMap, HashSet};
 
 use syn::Ident;
 
-use crate::types::{Defs, UnresolvedDefs};
+use crate::types::{
+    BaseEntry, Defs, Dimensions, Exponent, ExponentType, QuantityDefinition, QuantityEntry, Unit,
+    UnitEntry, UnitKind, UnresolvedDefs,
+};
+use crate::verify::Errors;
 
 use self::{
+    declared_dimension::check_declared_dimensions,
     error::{Error, Result},
     item::{IdentOrFactor, ResolvedItem, UnresolvedItem, ValueOrExpr},
     item_conversion::ItemConversion,
@@ -17,25 +23,70 @@ use self::{
 };
 
 impl UnresolvedDefs {
-    pub fn resolve(self) -> Result<Defs> {
+    pub fn resolve(self) -> syn::Result<Defs> {
+        let units: Vec<_> = self
+            .units
+            .into_iter()
+            .flat_map(|unit| unit.expand_prefixes())
+            .collect();
         let items: Vec<UnresolvedItem> = self
             .quantities
             .iter()
             .map(|q| q.to_unresolved_item())
-            .chain(self.units.iter().map(|u| u.to_unresolved_item()))
+            .chain(units.iter().map(|u| u.to_unresolved_item()))
             .chain(self.constants.iter().map(|u| u.to_unresolved_item()))
             .collect();
-        check_no_undefined_identifiers(&items)?;
+        let non_linear_units: HashSet<Ident> = units
+            .iter()
+            .filter(|unit| !matches!(unit.kind, UnitKind::Linear))
+            .map(|unit| unit.name.clone())
+            .collect();
+        let mut errors = Errors::default();
+        errors.add(check_no_undefined_identifiers(&items));
+        errors.add(check_no_non_linear_unit_references(
+            &items,
+            &non_linear_units,
+        ));
+        errors.add(check_no_duplicate_names(&items));
+        for error in duplicate_symbol_errors(&units) {
+            errors.push(error);
+        }
+        for error in duplicate_base_errors(&self.bases) {
+            errors.push(error);
+        }
+        errors.finish()?;
+        let dimension_fields = dimension_fields(&self.quantities, &self.bases);
         let mut items = Resolver::resolve(items)?;
+        check_declared_dimensions(&self.quantities, &units, &self.constants, &items)?;
         let quantities = convert_vec_to_resolved(self.quantities, &mut items);
-        let units = convert_vec_to_resolved(self.units, &mut items);
-        let constants = convert_vec_to_resolved(self.constants, &mut items);
+        let mut units = convert_vec_to_resolved(units, &mut items);
+        let mut constants = convert_vec_to_resolved(self.constants, &mut items);
+        let mut errors = Errors::default();
+        let scales = errors.add(get_base_scales(&self.bases, &units));
+        errors.add(check_exponents_in_range(
+            quantities
+                .iter()
+                .map(|q| (&q.name, &q.dimension))
+                .chain(units.iter().map(|u| (&u.name, &u.dimension)))
+                .chain(constants.iter().map(|c| (&c.name, &c.dimension))),
+            self.exponent_type,
+        ));
+        errors.finish()?;
+        let scales = scales.unwrap();
+        for unit in units.iter_mut() {
+            unit.factor /= unit.dimension.base_scale(&scales);
+        }
+        for constant in constants.iter_mut() {
+            constant.factor /= constant.dimension.base_scale(&scales);
+        }
         Ok(Defs {
             dimension_type: self.dimension_type,
             quantity_type: self.quantity_type,
             quantities,
             units,
             constants,
+            storage_types: self.storage_types,
+            dimension_fields,
         })
     }
 }
@@ -78,3 +129,155 @@ fn check_no_undefined_identifiers(items: &[UnresolvedItem]) -> Result<()> {
         Err(Error::undefined(undefined_rhs_idents))
     }
 }
+
+fn check_no_non_linear_unit_references(
+    items: &[UnresolvedItem],
+    non_linear_units: &HashSet<Ident>,
+) -> Result<()> {
+    let mut references = vec![];
+    for item in items.iter() {
+        if let ValueOrExpr::Expr(expr) = &item.val {
+            for val in expr.iter_vals() {
+                if let IdentOrFactor::Ident(ident) = val {
+                    if non_linear_units.contains(ident) {
+                        references.push(ident.clone());
+                    }
+                }
+            }
+        }
+    }
+    if references.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::non_linear_unit_reference(references))
+    }
+}
+
+/// Only the later definitions of a name are reported, since the
+/// first one is the one that the others conflict with.
+fn check_no_duplicate_names(items: &[UnresolvedItem]) -> Result<()> {
+    let mut names = HashSet::new();
+    let duplicates: Vec<_> = items
+        .iter()
+        .filter(|item| !names.insert(&item.name))
+        .map(|item| item.name.clone())
+        .collect();
+    if duplicates.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::duplicate_definition(duplicates))
+    }
+}
+
+/// Two units with the same symbol would make parsing and
+/// printing of quantities ambiguous. The errors are reported
+/// at the names of the later units.
+fn duplicate_symbol_errors(units: &[UnitEntry]) -> Vec<Error> {
+    let mut symbols = HashSet::new();
+    units
+        .iter()
+        .filter_map(|unit| {
+            let symbol = unit.symbol.as_ref()?;
+            (!symbols.insert(symbol))
+                .then(|| Error::duplicate_symbol(symbol.clone(), vec![unit.name.clone()]))
+        })
+        .collect()
+}
+
+/// Every field of the dimension type which is named in a definition
+/// or a base entry, at the span at which it is named.
+fn dimension_fields(quantities: &[QuantityEntry], bases: &[BaseEntry]) -> Vec<Ident> {
+    quantities
+        .iter()
+        .filter_map(|quantity| match &quantity.rhs {
+            QuantityDefinition::Dimensions(dimensions) => Some(dimensions),
+            QuantityDefinition::Expression(_) => None,
+        })
+        .flat_map(|dimensions| dimensions.fields.iter().map(|field| field.ident.clone()))
+        .chain(bases.iter().map(|base| base.dimension.clone()))
+        .collect()
+}
+
+/// Two base units for the same dimension would make the base
+/// representation ambiguous. The errors are reported at the
+/// dimensions of both the first and the later base entry.
+fn duplicate_base_errors(bases: &[BaseEntry]) -> Vec<Error> {
+    bases
+        .iter()
+        .enumerate()
+        .filter_map(|(index, base)| {
+            let first = bases[..index]
+                .iter()
+                .find(|first| first.dimension == base.dimension)?;
+            Some(Error::duplicate_base(vec![
+                first.dimension.clone(),
+                base.dimension.clone(),
+            ]))
+        })
+        .collect()
+}
+
+fn check_exponents_in_range<'a>(
+    dimensions: impl Iterator<Item = (&'a Ident, &'a Dimensions)>,
+    exponent_type: ExponentType,
+) -> Result<()> {
+    let overflowing: Vec<_> = dimensions
+        .filter(|(_, dimension)| {
+            dimension
+                .fields
+                .iter()
+                .any(|field| !exponent_type.contains(field.value))
+        })
+        .map(|(name, _)| name.clone())
+        .collect();
+    if overflowing.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::exponent_overflow(overflowing))
+    }
+}
+
+/// Find the factors of the base units given by the `base` entries,
+/// in terms of the base representation used in the definitions.
+fn get_base_scales(bases: &[BaseEntry], units: &[Unit]) -> Result<Vec<(Ident, f64)>> {
+    let mut scales = vec![];
+    let mut undefined = vec![];
+    let mut invalid = vec![];
+    for base in bases.iter() {
+        let unit = units.iter().find(|unit| unit.name == base.unit);
+        let is_valid = |unit: &Unit| {
+            let expected_value = |field: &Ident| {
+                if field == &base.dimension {
+                    Exponent::int(1)
+                } else {
+                    Exponent::int(0)
+                }
+            };
+            matches!(unit.kind, UnitKind::Linear)
+                && unit
+                    .dimension
+                    .fields
+                    .iter()
+                    .any(|field| field.ident == base.dimension)
+                && unit
+                    .dimension
+                    .fields
+                    .iter()
+                    .all(|field| field.value == expected_value(&field.ident))
+        };
+        match unit {
+            Some(unit) if is_valid(unit) => {
+                scales.push((base.dimension.clone(), unit.factor));
+            }
+            Some(_) => invalid.push(base.unit.clone()),
+            None => undefined.push(base.unit.clone()),
+        }
+    }
+    if !undefined.is_empty() {
+        Err(Error::undefined(undefined))
+    } else if !invalid.is_empty() {
+        Err(Error::invalid_base_unit(invalid))
+    } else {
+        Ok(scales)
+    }
+}
@@ -1,35 +1,57 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 
 use syn::Ident;
 
-use super::{error::Error, error::Result};
+use crate::verify::Errors;
+
+use super::error::{Error, Result};
 
 pub trait Resolvable {
     type Resolved;
 
-    fn resolve(self, others: &HashMap<Ident, Self::Resolved>) -> Self::Resolved;
+    fn resolve(self, others: &HashMap<Ident, Self::Resolved>) -> Result<Self::Resolved>;
     fn is_resolvable(&self, others: &HashMap<Ident, Self::Resolved>) -> bool;
     fn ident(&self) -> Ident;
+    /// The identifiers referenced in the definition, at the
+    /// span at which they are referenced.
+    fn dependencies(&self) -> Vec<Ident>;
 }
 
 pub struct Resolver<U, R> {
     unresolved: Vec<U>,
     resolved: HashMap<Ident, R>,
+    /// The items which could not be resolved, either because of
+    /// an error or because they depend on such an item.
+    failed: HashSet<Ident>,
 }
 
 impl<U: Resolvable<Resolved = R>, R> Resolver<U, R> {
-    pub fn resolve(unresolved: Vec<U>) -> Result<HashMap<Ident, R>> {
+    pub fn resolve(unresolved: Vec<U>) -> syn::Result<HashMap<Ident, R>> {
         let mut resolver = Self {
             unresolved,
             resolved: HashMap::new(),
+            failed: HashSet::new(),
         };
         resolver.run()?;
         Ok(resolver.resolved)
     }
 
-    fn run(&mut self) -> Result<()> {
+    /// Only the errors of the items which fail to resolve themselves
+    /// are reported, not those of the items depending on them.
+    fn run(&mut self) -> syn::Result<()> {
+        let mut errors = Errors::default();
         // This is a very inefficient topological sort.
         while !self.unresolved.is_empty() {
+            let next_failed = self.unresolved.iter().position(|x| {
+                x.dependencies()
+                    .iter()
+                    .any(|dependency| self.failed.contains(dependency))
+            });
+            if let Some(index) = next_failed {
+                let next_failed = self.unresolved.remove(index);
+                self.failed.insert(next_failed.ident());
+                continue;
+            }
             let next_resolvable = self
                 .unresolved
                 .iter()
@@ -38,14 +60,185 @@ impl<U: Resolvable<Resolved = R>, R> Resolver<U, R> {
             if let Some((index, _)) = next_resolvable {
                 let next_resolvable = self.unresolved.remove(index);
                 let name = next_resolvable.ident();
-                let resolved = next_resolvable.resolve(&self.resolved);
-                self.resolved.insert(name, resolved);
+                match next_resolvable.resolve(&self.resolved) {
+                    Ok(resolved) => {
+                        self.resolved.insert(name, resolved);
+                    }
+                    Err(error) => {
+                        errors.push(error);
+                        self.failed.insert(name);
+                    }
+                }
             } else {
-                return Err(Error::unresolvable(
-                    self.unresolved.drain(..).map(|x| x.ident()).collect(),
-                ));
+                errors.push(DependencyGraph::new(&self.unresolved).cycle_errors());
+                break;
+            }
+        }
+        errors.finish()
+    }
+}
+
+/// The dependencies between the items which could not be resolved.
+/// Since all referenced identifiers are defined, every one of these
+/// items is either part of a cycle or depends on one.
+struct DependencyGraph {
+    names: Vec<Ident>,
+    /// For each item, the items it references, along with the
+    /// identifier of the reference.
+    edges: Vec<Vec<(usize, Ident)>>,
+}
+
+impl DependencyGraph {
+    fn new<U: Resolvable>(items: &[U]) -> Self {
+        let names: Vec<_> = items.iter().map(|item| item.ident()).collect();
+        let indices: HashMap<&Ident, usize> = names
+            .iter()
+            .enumerate()
+            .map(|(index, name)| (name, index))
+            .collect();
+        let edges = items
+            .iter()
+            .map(|item| {
+                item.dependencies()
+                    .into_iter()
+                    .filter_map(|reference| Some((*indices.get(&reference)?, reference)))
+                    .collect()
+            })
+            .collect();
+        Self { names, edges }
+    }
+
+    /// An error for each cycle, showing the path along the cycle, and
+    /// a single error listing the items which only depend on a cycle.
+    fn cycle_errors(&self) -> syn::Error {
+        let mut errors = Errors::default();
+        let mut on_cycle = vec![false; self.names.len()];
+        for component in self.strongly_connected_components() {
+            let start = component[0];
+            let is_cycle =
+                component.len() > 1 || self.edges[start].iter().any(|(to, _)| *to == start);
+            if !is_cycle {
+                continue;
+            }
+            for &index in component.iter() {
+                on_cycle[index] = true;
+            }
+            let (path, references) = self.find_cycle(start, &component);
+            errors.push(Error::cycle(path, references));
+        }
+        let dependents: Vec<_> = self
+            .names
+            .iter()
+            .zip(on_cycle)
+            .filter(|(_, on_cycle)| !on_cycle)
+            .map(|(name, _)| name.clone())
+            .collect();
+        if !dependents.is_empty() {
+            errors.push(Error::depends_on_cycle(dependents));
+        }
+        errors
+            .finish()
+            .expect_err("Unresolvable items without a cycle.")
+    }
+
+    /// The strongly connected components of the graph, via Tarjan's
+    /// algorithm. The items of each component are sorted by their index.
+    fn strongly_connected_components(&self) -> Vec<Vec<usize>> {
+        let mut tarjan = Tarjan {
+            graph: self,
+            index: vec![None; self.names.len()],
+            low_link: vec![0; self.names.len()],
+            on_stack: vec![false; self.names.len()],
+            stack: vec![],
+            next_index: 0,
+            components: vec![],
+        };
+        for node in 0..self.names.len() {
+            if tarjan.index[node].is_none() {
+                tarjan.visit(node);
+            }
+        }
+        let mut components = tarjan.components;
+        for component in components.iter_mut() {
+            component.sort();
+        }
+        components.sort();
+        components
+    }
+
+    /// The shortest cycle through `start` within the component. Returns
+    /// the names along the cycle, starting and ending at `start`, and
+    /// the references which form its edges.
+    fn find_cycle(&self, start: usize, component: &[usize]) -> (Vec<Ident>, Vec<Ident>) {
+        let mut parents: HashMap<usize, (usize, &Ident)> = HashMap::new();
+        let mut queue = VecDeque::from([start]);
+        while let Some(node) = queue.pop_front() {
+            for (to, reference) in self.edges[node].iter() {
+                if *to == start {
+                    let mut path = vec![self.names[start].clone()];
+                    let mut references = vec![reference.clone()];
+                    let mut current = node;
+                    while current != start {
+                        let (parent, reference) = parents[&current];
+                        path.push(self.names[current].clone());
+                        references.push(reference.clone());
+                        current = parent;
+                    }
+                    path.push(self.names[start].clone());
+                    path.reverse();
+                    references.reverse();
+                    return (path, references);
+                }
+                if component.contains(to) && !parents.contains_key(to) {
+                    parents.insert(*to, (node, reference));
+                    queue.push_back(*to);
+                }
+            }
+        }
+        unreachable!("No cycle through a node of a strongly connected component.")
+    }
+}
+
+struct Tarjan<'a> {
+    graph: &'a DependencyGraph,
+    index: Vec<Option<usize>>,
+    low_link: Vec<usize>,
+    on_stack: Vec<bool>,
+    stack: Vec<usize>,
+    next_index: usize,
+    components: Vec<Vec<usize>>,
+}
+
+impl Tarjan<'_> {
+    fn visit(&mut self, node: usize) {
+        self.index[node] = Some(self.next_index);
+        self.low_link[node] = self.next_index;
+        self.next_index += 1;
+        self.stack.push(node);
+        self.on_stack[node] = true;
+        for (to, _) in self.graph.edges[node].iter() {
+            match self.index[*to] {
+                None => {
+                    self.visit(*to);
+                    self.low_link[node] = self.low_link[node].min(self.low_link[*to]);
+                }
+                Some(index) if self.on_stack[*to] => {
+                    self.low_link[node] = self.low_link[node].min(index);
+                }
+                Some(_) => {}
+            }
+        }
+        if Some(self.low_link[node]) == self.index[node] {
+            let mut component = vec![];
+            loop {
+                let member = self.stack.pop().unwrap();
+                self.on_stack[member] = false;
+                component.push(member);
+                if member == node {
+                    break;
+                }
             }
+            self.components.push(component);
         }
-        Ok(())
     }
 }
@@ -1,8 +1,8 @@
 use proc_macro2::TokenStream;
-use quote::quote;
-use syn::Type;
+use quote::{format_ident, quote};
+use syn::{Ident, Type};
 
-use crate::types::Defs;
+use crate::types::{Defs, Unit, UnitKind};
 
 pub struct VectorType {
     pub name: Type,
@@ -11,6 +11,23 @@ pub struct VectorType {
     pub num_dims: usize,
 }
 
+/// An integer vector type from `glam`. As for the integer storage
+/// types, units can only be constructed if their factor is an integer.
+pub struct IntVectorType {
+    pub name: Type,
+    pub module_name: TokenStream,
+    pub int_type: IntType,
+    pub num_dims: usize,
+}
+
+/// The names of the components of a vector with the given number of dimensions.
+pub fn vector_components(num_dims: usize) -> Vec<Ident> {
+    ["x", "y", "z", "w"][..num_dims]
+        .iter()
+        .map(|component| format_ident!("{}", component))
+        .collect()
+}
+
 pub struct FloatType {
     pub name: Type,
     pub module_name: TokenStream,
@@ -22,37 +39,472 @@ pub struct FloatType {
     pub serialize_method: TokenStream,
 }
 
+/// A complex number type from `num-complex`, whose real and
+/// imaginary parts are of the given float type.
+pub struct ComplexType {
+    pub name: Type,
+    pub module_name: TokenStream,
+    pub float_type: FloatType,
+}
+
+/// The `ndarray` arrays with elements of the given float type. Quantities
+/// are generic over the dimension `Ix` of the array, while the numerical
+/// traits with plain arrays are implemented for the arrays of
+/// the dimensions in `concrete_types`.
+pub struct ArrayType {
+    /// The array type, with the type parameter `Ix` as its dimension.
+    pub name: Type,
+    pub module_name: TokenStream,
+    pub float_type: FloatType,
+    pub concrete_types: Vec<Type>,
+}
+
+/// What a `nalgebra` storage type represents.
+#[cfg_attr(not(feature = "nalgebra"), allow(dead_code))]
+pub enum NalgebraKind {
+    Vector,
+    Matrix,
+    /// Rotations can only be stored in dimensionless quantities,
+    /// so there are no unit constructors for them.
+    Rotation,
+}
+
+/// A vector, matrix or rotation type from `nalgebra`,
+/// with elements of the given float type.
+pub struct NalgebraType {
+    pub name: Type,
+    pub module_name: TokenStream,
+    pub float_type: FloatType,
+    pub kind: NalgebraKind,
+}
+
+/// An integer storage type. Units can only be constructed
+/// with integer storage if their factor is an integer.
+pub struct IntType {
+    pub name: Type,
+    pub module_name: TokenStream,
+    pub num_bits: i32,
+    #[cfg(feature = "serde")]
+    pub serialize_method: TokenStream,
+}
+
+impl IntType {
+    /// Whether the given factor is an integer which can
+    /// be represented by this type.
+    pub fn represents(&self, factor: f64) -> bool {
+        factor.fract() == 0.0 && factor.abs() < 2.0f64.powi(self.num_bits - 1)
+    }
+
+    /// Whether the factor and the offset of the unit are representable.
+    pub fn represents_unit(&self, unit: &Unit) -> bool {
+        self.represents(unit.factor)
+            && match unit.kind {
+                UnitKind::Affine { offset } => self.represents(offset),
+                _ => true,
+            }
+    }
+
+    /// Whether a value of this type can be converted into the unit
+    /// without rounding, i.e. whether the inverse of the factor (up to
+    /// the error of the floating point division) and the offset of the
+    /// unit are representable.
+    pub fn converts_unit(&self, unit: &Unit) -> bool {
+        let inverse = 1.0 / unit.factor;
+        let rounded = inverse.round();
+        (inverse - rounded).abs() <= 4.0 * f64::EPSILON * rounded.abs()
+            && rounded != 0.0
+            && self.represents(rounded)
+            && match unit.kind {
+                UnitKind::Affine { offset } => self.represents(offset),
+                _ => true,
+            }
+    }
+}
+
+/// A storage type declared in a `storage` entry of the unit system,
+/// which implements `diman::StorageType`. In contrast to the built-in
+/// storage types, nothing is known about its methods.
+pub struct UserStorageType {
+    pub name: Type,
+    pub module_name: TokenStream,
+}
+
+/// How the constructors of a unit take and build the value
+/// of a storage type.
+pub struct Constructor {
+    pub fn_args: TokenStream,
+    /// The value in the unit, built from the arguments.
+    pub value: TokenStream,
+    /// The type to which the factor and offset of the unit are cast.
+    /// If `None`, they are applied via `diman::StorageType`.
+    pub scalar: Option<TokenStream>,
+}
+
+/// The type aliases, unit constructors, conversions and numeric traits
+/// are generated for the built-in storage types and the ones declared
+/// in `storage` entries alike, from the description of the type given
+/// by this trait. Only the methods which are specific to one kind of
+/// storage type, such as `sqrt` for floats, are generated separately.
 pub trait StorageType {
     fn name(&self) -> &Type;
+    fn module_name(&self) -> &TokenStream;
+    /// The type of the elements, to which the values of constants are cast.
     fn base_storage(&self) -> &Type;
+
+    /// The generic parameters of the impls for this type.
+    fn impl_generics(&self) -> TokenStream {
+        quote! {}
+    }
+
+    /// The generic parameters of the type aliases for this type.
+    fn alias_generics(&self) -> TokenStream {
+        quote! {}
+    }
+
+    /// The types for which the numeric traits are implemented.
+    fn trait_types(&self) -> Vec<Type> {
+        vec![self.name().clone()]
+    }
+
+    fn has_constants(&self) -> bool {
+        false
+    }
+
+    /// Whether only dimensionless quantities can be stored in this type.
+    fn is_dimensionless_only(&self) -> bool {
+        false
+    }
+
+    /// How the unit constructors build a value of this type, or `None`
+    /// if there are no unit constructors for it.
+    fn constructor(&self) -> Option<Constructor>;
+
+    fn has_constructor(&self, _unit: &Unit) -> bool {
+        true
+    }
+
+    fn has_conversion(&self, _unit: &Unit) -> bool {
+        true
+    }
 }
 
+/// Since constants are generally not integers, they are only defined
+/// for the float types.
+impl StorageType for FloatType {
+    fn name(&self) -> &Type {
+        &self.name
+    }
+
+    fn module_name(&self) -> &TokenStream {
+        &self.module_name
+    }
+
+    fn base_storage(&self) -> &Type {
+        &self.name
+    }
+
+    fn has_constants(&self) -> bool {
+        true
+    }
+
+    fn constructor(&self) -> Option<Constructor> {
+        let name = &self.name;
+        Some(Constructor {
+            fn_args: quote! { val: #name },
+            value: quote! { val },
+            scalar: Some(quote! { #name }),
+        })
+    }
+}
+
+/// Constructors for integer storage types are only generated for
+/// units whose factor (and offset) are integers, so that using any
+/// other unit with an integer storage type fails to compile. Since
+/// the conversions round the result, they are only generated for
+/// units into which the conversion is exact, such as `in_millimeters`
+/// but not `in_kilometers`. The factor and offset are applied via
+/// `diman::StorageType`, which uses exact integer arithmetic and panics
+/// on overflow instead of wrapping around in release builds.
+impl StorageType for IntType {
+    fn name(&self) -> &Type {
+        &self.name
+    }
+
+    fn module_name(&self) -> &TokenStream {
+        &self.module_name
+    }
+
+    fn base_storage(&self) -> &Type {
+        &self.name
+    }
+
+    fn constructor(&self) -> Option<Constructor> {
+        let name = &self.name;
+        Some(Constructor {
+            fn_args: quote! { val: #name },
+            value: quote! { val },
+            scalar: None,
+        })
+    }
+
+    fn has_constructor(&self, unit: &Unit) -> bool {
+        self.represents_unit(unit)
+    }
+
+    fn has_conversion(&self, unit: &Unit) -> bool {
+        self.converts_unit(unit)
+    }
+}
+
+/// The user storage types are only known to implement `diman::StorageType`,
+/// so the unit factors are applied via its methods.
+impl StorageType for UserStorageType {
+    fn name(&self) -> &Type {
+        &self.name
+    }
+
+    fn module_name(&self) -> &TokenStream {
+        &self.module_name
+    }
+
+    fn base_storage(&self) -> &Type {
+        &self.name
+    }
+
+    fn constructor(&self) -> Option<Constructor> {
+        let name = &self.name;
+        Some(Constructor {
+            fn_args: quote! { val: #name },
+            value: quote! { val },
+            scalar: None,
+        })
+    }
+}
+
+/// Vectors are constructed from their components.
 impl StorageType for VectorType {
     fn name(&self) -> &Type {
         &self.name
     }
 
+    fn module_name(&self) -> &TokenStream {
+        &self.module_name
+    }
+
     fn base_storage(&self) -> &Type {
         &self.float_type.name
     }
+
+    fn constructor(&self) -> Option<Constructor> {
+        let name = &self.name;
+        let float_type = &self.float_type.name;
+        let components = vector_components(self.num_dims);
+        Some(Constructor {
+            fn_args: quote! { #(#components: #float_type),* },
+            value: quote! { #name::new(#(#components),*) },
+            scalar: Some(quote! { #float_type }),
+        })
+    }
 }
 
-impl StorageType for FloatType {
+/// Like for `IntType`, only units whose factor (and offset)
+/// is an integer get a constructor, and overflow panics.
+impl StorageType for IntVectorType {
+    fn name(&self) -> &Type {
+        &self.name
+    }
+
+    fn module_name(&self) -> &TokenStream {
+        &self.module_name
+    }
+
+    fn base_storage(&self) -> &Type {
+        &self.int_type.name
+    }
+
+    fn constructor(&self) -> Option<Constructor> {
+        let name = &self.name;
+        let scalar = &self.int_type.name;
+        let components = vector_components(self.num_dims);
+        Some(Constructor {
+            fn_args: quote! { #(#components: #scalar),* },
+            value: quote! { #name::new(#(#components),*) },
+            scalar: None,
+        })
+    }
+
+    fn has_constructor(&self, unit: &Unit) -> bool {
+        self.int_type.represents_unit(unit)
+    }
+
+    fn has_conversion(&self, unit: &Unit) -> bool {
+        self.int_type.converts_unit(unit)
+    }
+}
+
+impl StorageType for ComplexType {
     fn name(&self) -> &Type {
         &self.name
     }
 
+    fn module_name(&self) -> &TokenStream {
+        &self.module_name
+    }
+
     fn base_storage(&self) -> &Type {
+        &self.float_type.name
+    }
+
+    fn constructor(&self) -> Option<Constructor> {
+        let name = &self.name;
+        let float_type = &self.float_type.name;
+        Some(Constructor {
+            fn_args: quote! { re: #float_type, im: #float_type },
+            value: quote! { <#name>::new(re, im) },
+            scalar: Some(quote! { #float_type }),
+        })
+    }
+}
+
+/// The type aliases for arrays are generic over the dimension
+/// of the array and default to one-dimensional arrays.
+impl StorageType for ArrayType {
+    fn name(&self) -> &Type {
         &self.name
     }
+
+    fn module_name(&self) -> &TokenStream {
+        &self.module_name
+    }
+
+    fn base_storage(&self) -> &Type {
+        &self.float_type.name
+    }
+
+    fn impl_generics(&self) -> TokenStream {
+        quote! { <Ix: ::ndarray::Dimension> }
+    }
+
+    fn alias_generics(&self) -> TokenStream {
+        quote! { <Ix = ::ndarray::Ix1> }
+    }
+
+    fn trait_types(&self) -> Vec<Type> {
+        self.concrete_types.clone()
+    }
+
+    fn constructor(&self) -> Option<Constructor> {
+        let name = &self.name;
+        let float_type = &self.float_type.name;
+        Some(Constructor {
+            fn_args: quote! { val: #name },
+            value: quote! { val },
+            scalar: Some(quote! { #float_type }),
+        })
+    }
+}
+
+/// Vectors are constructed from their components, like the `glam` vectors,
+/// while matrices are constructed from a `nalgebra` matrix. The offsets are
+/// added via `diman::StorageType`, since `nalgebra` only adds scalars to
+/// vectors and matrices via `add_scalar`.
+impl StorageType for NalgebraType {
+    fn name(&self) -> &Type {
+        &self.name
+    }
+
+    fn module_name(&self) -> &TokenStream {
+        &self.module_name
+    }
+
+    fn base_storage(&self) -> &Type {
+        &self.float_type.name
+    }
+
+    fn trait_types(&self) -> Vec<Type> {
+        match self.kind {
+            NalgebraKind::Rotation => vec![],
+            _ => vec![self.name.clone()],
+        }
+    }
+
+    fn is_dimensionless_only(&self) -> bool {
+        matches!(self.kind, NalgebraKind::Rotation)
+    }
+
+    fn constructor(&self) -> Option<Constructor> {
+        let name = &self.name;
+        let float_type = &self.float_type.name;
+        let (fn_args, value) = match self.kind {
+            NalgebraKind::Vector => (
+                quote! { x: #float_type, y: #float_type, z: #float_type },
+                quote! { <#name>::new(x, y, z) },
+            ),
+            NalgebraKind::Matrix => (quote! { val: #name }, quote! { val }),
+            NalgebraKind::Rotation => return None,
+        };
+        Some(Constructor {
+            fn_args,
+            value,
+            scalar: None,
+        })
+    }
+
+    fn has_conversion(&self, _unit: &Unit) -> bool {
+        !matches!(self.kind, NalgebraKind::Rotation)
+    }
 }
 
 impl Defs {
+    /// All storage types, built-in and declared in `storage` entries.
+    pub fn all_storage_types(&self) -> Vec<Box<dyn StorageType>> {
+        fn boxed<T: StorageType + 'static>(
+            types: Vec<T>,
+        ) -> impl Iterator<Item = Box<dyn StorageType>> {
+            types
+                .into_iter()
+                .map(|type_| Box::new(type_) as Box<dyn StorageType>)
+        }
+        boxed(self.float_types())
+            .chain(boxed(self.int_types()))
+            .chain(boxed(self.user_storage_types()))
+            .chain(boxed(self.vector_types()))
+            .chain(boxed(self.int_vector_types()))
+            .chain(boxed(self.complex_types()))
+            .chain(boxed(self.array_types()))
+            .chain(boxed(self.nalgebra_types()))
+            .collect()
+    }
+
     pub fn storage_type_names(&self) -> Vec<Type> {
+        self.all_storage_types()
+            .iter()
+            .flat_map(|type_| type_.trait_types())
+            .collect()
+    }
+
+    /// The built-in storage types whose values are real numbers
+    /// or vectors of them, which share methods such as `abs`.
+    pub fn real_storage_type_names(&self) -> Vec<Type> {
         self.float_types()
             .into_iter()
             .map(|x| x.name)
+            .chain(self.int_types().into_iter().map(|x| x.name))
             .chain(self.vector_types().into_iter().map(|x| x.name))
+            .chain(self.int_vector_types().into_iter().map(|x| x.name))
+            .collect()
+    }
+
+    pub fn user_storage_types(&self) -> Vec<UserStorageType> {
+        self.storage_types
+            .iter()
+            .map(|entry| {
+                let module_name = &entry.module_name;
+                UserStorageType {
+                    name: entry.type_.clone(),
+                    module_name: quote! { #module_name },
+                }
+            })
             .collect()
     }
 
@@ -62,6 +514,8 @@ impl Defs {
         let _dvec2: Type = syn::parse2(quote! { ::glam::DVec2 }).unwrap();
         let _vec3: Type = syn::parse2(quote! { ::glam::Vec3 }).unwrap();
         let _dvec3: Type = syn::parse2(quote! { ::glam::DVec3 }).unwrap();
+        let _vec4: Type = syn::parse2(quote! { ::glam::Vec4 }).unwrap();
+        let _dvec4: Type = syn::parse2(quote! { ::glam::DVec4 }).unwrap();
         vec![
             #[cfg(feature = "glam-vec2")]
             VectorType {
@@ -91,6 +545,107 @@ impl Defs {
                 float_type: self.f64_type(),
                 num_dims: 3,
             },
+            #[cfg(feature = "glam-vec4")]
+            VectorType {
+                name: _vec4,
+                module_name: quote! { vec4 },
+                float_type: self.f32_type(),
+                num_dims: 4,
+            },
+            #[cfg(feature = "glam-dvec4")]
+            VectorType {
+                name: _dvec4,
+                module_name: quote! { dvec4 },
+                float_type: self.f64_type(),
+                num_dims: 4,
+            },
+        ]
+    }
+
+    pub fn int_vector_types(&self) -> Vec<IntVectorType> {
+        let _ivec2: Type = syn::parse2(quote! { ::glam::IVec2 }).unwrap();
+        let _ivec3: Type = syn::parse2(quote! { ::glam::IVec3 }).unwrap();
+        vec![
+            #[cfg(feature = "glam-ivec2")]
+            IntVectorType {
+                name: _ivec2,
+                module_name: quote! { ivec2 },
+                int_type: self.i32_type(),
+                num_dims: 2,
+            },
+            #[cfg(feature = "glam-ivec3")]
+            IntVectorType {
+                name: _ivec3,
+                module_name: quote! { ivec3 },
+                int_type: self.i32_type(),
+                num_dims: 3,
+            },
+        ]
+    }
+
+    pub fn complex_types(&self) -> Vec<ComplexType> {
+        let _complex32: Type = syn::parse2(quote! { ::num_complex::Complex32 }).unwrap();
+        let _complex64: Type = syn::parse2(quote! { ::num_complex::Complex64 }).unwrap();
+        vec![
+            #[cfg(feature = "num-complex")]
+            ComplexType {
+                name: _complex32,
+                module_name: quote! { complex32 },
+                float_type: self.f32_type(),
+            },
+            #[cfg(feature = "num-complex")]
+            ComplexType {
+                name: _complex64,
+                module_name: quote! { complex64 },
+                float_type: self.f64_type(),
+            },
+        ]
+    }
+
+    pub fn array_types(&self) -> Vec<ArrayType> {
+        vec![
+            #[cfg(feature = "ndarray")]
+            ArrayType {
+                name: syn::parse2(quote! { ::ndarray::Array<f64, Ix> }).unwrap(),
+                module_name: quote! { array },
+                float_type: self.f64_type(),
+                concrete_types: vec![
+                    syn::parse2(quote! { ::ndarray::Array1<f64> }).unwrap(),
+                    syn::parse2(quote! { ::ndarray::Array2<f64> }).unwrap(),
+                    syn::parse2(quote! { ::ndarray::Array3<f64> }).unwrap(),
+                    syn::parse2(quote! { ::ndarray::ArrayD<f64> }).unwrap(),
+                ],
+            },
+        ]
+    }
+
+    pub fn nalgebra_types(&self) -> Vec<NalgebraType> {
+        let _vector3: Type = syn::parse2(quote! { ::nalgebra::Vector3<f64> }).unwrap();
+        let _matrix3: Type = syn::parse2(quote! { ::nalgebra::Matrix3<f64> }).unwrap();
+        let _unit_quaternion: Type =
+            syn::parse2(quote! { ::nalgebra::UnitQuaternion<f64> }).unwrap();
+        vec![
+            #[cfg(feature = "nalgebra")]
+            NalgebraType {
+                name: _vector3,
+                module_name: quote! { vector3 },
+                float_type: self.f64_type(),
+                kind: NalgebraKind::Vector,
+            },
+            #[cfg(feature = "nalgebra")]
+            NalgebraType {
+                name: _matrix3,
+                module_name: quote! { matrix3 },
+                float_type: self.f64_type(),
+                kind: NalgebraKind::Matrix,
+            },
+            #[cfg(feature = "nalgebra")]
+            NalgebraType {
+                name: _unit_quaternion,
+                module_name: quote! { unit_quaternion },
+                float_type: self.f64_type(),
+                kind: NalgebraKind::Rotation,
+            },
         ]
     }
 
@@ -132,4 +687,37 @@ impl Defs {
             self.f64_type(),
         ]
     }
+
+    #[cfg(feature = "i32")]
+    fn i32_type(&self) -> IntType {
+        let i32_ty: Type = syn::parse2(quote! { i32 }).unwrap();
+        IntType {
+            name: i32_ty,
+            module_name: quote! { i32 },
+            num_bits: 32,
+            #[cfg(feature = "serde")]
+            serialize_method: quote! { serialize_i32 },
+        }
+    }
+
+    #[cfg(feature = "i64")]
+    fn i64_type(&self) -> IntType {
+        let i64_ty: Type = syn::parse2(quote! { i64 }).unwrap();
+        IntType {
+            name: i64_ty,
+            module_name: quote! { i64 },
+            num_bits: 64,
+            #[cfg(feature = "serde")]
+            serialize_method: quote! { serialize_i64 },
+        }
+    }
+
+    pub fn int_types(&self) -> Vec<IntType> {
+        vec![
+            #[cfg(feature = "i32")]
+            self.i32_type(),
+            #[cfg(feature = "i64")]
+            self.i64_type(),
+        ]
+    }
 }
@@ -2,14 +2,22 @@ use syn::*;
 
 use crate::expression::Expr;
 
+#[derive(Clone)]
 pub struct Prefix {
-    pub name: String,
+    pub name: &'static str,
+    pub short: &'static str,
+    pub factor: f64,
 }
 
+/// A rational exponent of a base dimension. This is the same type
+/// as `diman::Ratio`, so that exponents are computed exactly as in
+/// the methods of a dimension type with fields of type `Ratio`.
+pub type Exponent = diman_lib::ratio::Ratio;
+
 #[derive(Clone)]
 pub struct DimensionEntry {
     pub ident: Ident,
-    pub value: i32,
+    pub value: Exponent,
 }
 
 #[derive(Clone)]
@@ -32,11 +40,37 @@ pub enum QuantityDefinition {
     Expression(QuantityExpression),
 }
 
+#[derive(Clone, Copy)]
+pub enum LogBase {
+    Ten,
+    Two,
+    E,
+}
+
+/// How the value of a unit relates to the quantity given by
+/// the right hand side of its definition.
+#[derive(Clone, Copy)]
+pub enum UnitKind {
+    /// The unit is a multiple of the right hand side.
+    Linear,
+    /// A unit with an offset, such as degrees Celsius. A value `x`
+    /// corresponds to `(x + offset)` times the right hand side.
+    Affine { offset: f64 },
+    /// A logarithmic unit, such as dBm. A value `x` corresponds to
+    /// `base^(x / scale)` times the right hand side.
+    Logarithmic { base: LogBase, scale: f64 },
+}
+
 pub struct UnitEntry {
     pub name: Ident,
     pub symbol: Option<String>,
     pub prefixes: Vec<Prefix>,
+    /// The quantity given on the left hand side, as in
+    /// `unit newtons: Force = ...`, whose dimension the
+    /// right hand side is checked against.
+    pub dimension: Option<Ident>,
     pub rhs: UnitExpression,
+    pub kind: UnitKind,
 }
 
 pub struct QuantityEntry {
@@ -46,15 +80,48 @@ pub struct QuantityEntry {
 
 pub struct ConstantEntry {
     pub name: Ident,
+    /// The quantity given on the left hand side, see `UnitEntry`.
+    pub dimension: Option<Ident>,
     pub rhs: UnitExpression,
 }
 
+/// Sets the unit in which the values of quantities are stored
+/// for one of the base dimensions, as in `base length = kiloparsecs`.
+pub struct BaseEntry {
+    pub dimension: Ident,
+    pub unit: Ident,
+}
+
+/// Declares an additional storage type implementing `diman::StorageType`,
+/// as in `storage f16 = half::f16`. The quantity type aliases for it
+/// are defined in a module with the given name.
+pub struct StorageTypeEntry {
+    pub module_name: Ident,
+    pub type_: Type,
+}
+
+/// The type of the fields of the dimension type,
+/// which determines the range of valid exponents.
+#[derive(Clone, Copy)]
+pub enum ExponentType {
+    /// The type was not given. The exponents are only checked
+    /// against the fields by the compiler, see `dimension_field_checks`.
+    Unspecified,
+    I8,
+    I32,
+    I64,
+    Ratio,
+}
+
 pub struct UnresolvedDefs {
     pub dimension_type: Type,
+    pub exponent_type: ExponentType,
     pub quantity_type: Type,
     pub quantities: Vec<QuantityEntry>,
     pub units: Vec<UnitEntry>,
     pub constants: Vec<ConstantEntry>,
+    pub bases: Vec<BaseEntry>,
+    pub storage_types: Vec<StorageTypeEntry>,
 }
 
 pub struct Quantity {
@@ -67,6 +134,7 @@ pub struct Unit {
     pub dimension: Dimensions,
     pub factor: f64,
     pub symbol: Option<String>,
+    pub kind: UnitKind,
 }
 
 pub struct Constant {
@@ -81,4 +149,8 @@ pub struct Defs {
     pub quantities: Vec<Quantity>,
     pub units: Vec<Unit>,
     pub constants: Vec<Constant>,
+    pub storage_types: Vec<StorageTypeEntry>,
+    /// The fields of the dimension type as they are named in the
+    /// definitions, so that they can be checked at their spans.
+    pub dimension_fields: Vec<Ident>,
 }
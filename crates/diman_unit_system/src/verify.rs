@@ -1,6 +1,6 @@
 use crate::parse::types as ptype;
 use crate::types::*;
-use syn::{Error, Lit, Result};
+use syn::{Error, Ident, Lit, Result};
 
 use crate::expression::{Expr, Factor};
 
@@ -10,10 +10,58 @@ pub trait Verify: Sized {
     fn verify(self) -> Result<Self::Verified>;
 }
 
+/// Collects the errors of verifying several independent values,
+/// so that all of them are reported instead of only the first one.
+#[derive(Default)]
+pub struct Errors(Option<Error>);
+
+impl Errors {
+    pub fn add<T, E: Into<Error>>(&mut self, result: std::result::Result<T, E>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(error) => {
+                self.push(error);
+                None
+            }
+        }
+    }
+
+    pub fn push(&mut self, error: impl Into<Error>) {
+        let error = error.into();
+        match &mut self.0 {
+            Some(errors) => errors.combine(error),
+            None => self.0 = Some(error),
+        }
+    }
+
+    /// Returns the combined errors, if there were any.
+    pub fn finish(self) -> Result<()> {
+        match self.0 {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Verify two independent values, reporting the errors of both.
+fn verify_both<A: Verify, B: Verify>(a: A, b: B) -> Result<(A::Verified, B::Verified)> {
+    let mut errors = Errors::default();
+    let a = errors.add(a.verify());
+    let b = errors.add(b.verify());
+    errors.finish()?;
+    Ok((a.unwrap(), b.unwrap()))
+}
+
 impl<T: Verify> Verify for Vec<T> {
     type Verified = Vec<<T as Verify>::Verified>;
     fn verify(self) -> Result<Self::Verified> {
-        self.into_iter().map(|x| x.verify()).collect()
+        let mut errors = Errors::default();
+        let verified: Vec<_> = self
+            .into_iter()
+            .filter_map(|x| errors.add(x.verify()))
+            .collect();
+        errors.finish()?;
+        Ok(verified)
     }
 }
 
@@ -44,8 +92,14 @@ impl<T: Verify> Verify for Expr<T> {
     fn verify(self) -> Result<Self::Verified> {
         Ok(match self {
             Expr::Value(val) => Expr::Value(val.verify()?),
-            Expr::Times(val, expr) => Expr::Times(val.verify()?, Box::new(expr.verify()?)),
-            Expr::Over(val, expr) => Expr::Over(val.verify()?, Box::new(expr.verify()?)),
+            Expr::Times(val, expr) => {
+                let (val, expr) = verify_both(val, *expr)?;
+                Expr::Times(val, Box::new(expr))
+            }
+            Expr::Over(val, expr) => {
+                let (val, expr) = verify_both(val, *expr)?;
+                Expr::Over(val, Box::new(expr))
+            }
         })
     }
 }
@@ -57,6 +111,7 @@ impl<T: Verify> Verify for Factor<T> {
         Ok(match self {
             Factor::Value(val) => Factor::Value(val.verify()?),
             Factor::ParenExpr(expr) => Factor::ParenExpr(Box::new(expr.verify()?)),
+            Factor::Power(factor, exponent) => Factor::Power(Box::new(factor.verify()?), exponent),
         })
     }
 }
@@ -90,8 +145,128 @@ impl Verify for ptype::Factor {
     }
 }
 
+/// The names `pi` and `e` are reserved for the constants which
+/// can be used in the definitions of units and constants.
+impl Verify for ptype::Name {
+    type Verified = Ident;
+
+    fn verify(self) -> Result<Self::Verified> {
+        if self.0 == "pi" || self.0 == "e" {
+            return Err(Error::new(
+                self.0.span(),
+                format!(
+                    "Reserved name: \"{}\"\nhelp: The names \"pi\" and \"e\" denote mathematical constants in definitions.",
+                    self.0
+                ),
+            ));
+        }
+        Ok(self.0)
+    }
+}
+
+impl Verify for ptype::UnitFactor {
+    type Verified = UnitFactor;
+
+    fn verify(self) -> Result<Self::Verified> {
+        Ok(match self {
+            ptype::UnitFactor::UnitOrQuantity(ident) => UnitFactor::UnitOrQuantity(ident),
+            ptype::UnitFactor::Number(factor) => UnitFactor::Number(factor.verify()?),
+            ptype::UnitFactor::Constant(ident) => UnitFactor::Number(if ident == "pi" {
+                std::f64::consts::PI
+            } else {
+                std::f64::consts::E
+            }),
+            ptype::UnitFactor::Sum(first, terms) => {
+                let (first, terms) = verify_both(first, terms)?;
+                UnitFactor::Number(first + terms.into_iter().sum::<f64>())
+            }
+        })
+    }
+}
+
+impl Verify for ptype::SignedFactor {
+    type Verified = f64;
+
+    fn verify(self) -> Result<Self::Verified> {
+        let value = self.value.verify()?;
+        Ok(match self.sign {
+            ptype::Sign::Plus => value,
+            ptype::Sign::Minus => -value,
+        })
+    }
+}
+
+impl Verify for ptype::Logarithm {
+    type Verified = UnitKind;
+
+    fn verify(self) -> Result<Self::Verified> {
+        let mut errors = Errors::default();
+        let base = errors.add(
+            LogBase::from_function_name(&self.function.to_string()).ok_or_else(|| {
+                Error::new(
+                    self.function.span(),
+                    format!("Unknown logarithm: \"{}\"", self.function),
+                )
+            }),
+        );
+        let scale = errors.add(self.scale.verify());
+        errors.finish()?;
+        Ok(UnitKind::Logarithmic {
+            base: base.unwrap(),
+            scale: scale.unwrap().unwrap_or(1.0),
+        })
+    }
+}
+
+impl Verify for ptype::UnitKind {
+    type Verified = UnitKind;
+
+    fn verify(self) -> Result<Self::Verified> {
+        Ok(match self {
+            ptype::UnitKind::Linear => UnitKind::Linear,
+            ptype::UnitKind::Affine(offset) => UnitKind::Affine {
+                offset: offset.verify()?,
+            },
+            ptype::UnitKind::Logarithmic(logarithm) => logarithm.verify()?,
+        })
+    }
+}
+
+impl Verify for ptype::ExponentType {
+    type Verified = ExponentType;
+
+    fn verify(self) -> Result<Self::Verified> {
+        match self.0 {
+            None => Ok(ExponentType::Unspecified),
+            Some(ident) => ExponentType::from_name(&ident.to_string()).ok_or_else(|| {
+                Error::new(
+                    ident.span(),
+                    "Unexpected exponent type, expected one of i8, i32, i64 or Ratio".to_string(),
+                )
+            }),
+        }
+    }
+}
+
+impl Verify for ptype::Exponent {
+    type Verified = Exponent;
+
+    fn verify(self) -> Result<Self::Verified> {
+        let span = self.numerator.0.span();
+        let (numerator, denominator) = verify_both(self.numerator, self.denominator)?;
+        let denominator = denominator.unwrap_or(1);
+        if denominator == 0 {
+            return Err(Error::new(
+                span,
+                "Denominator of an exponent cannot be zero".to_string(),
+            ));
+        }
+        Ok(Exponent::new(numerator, denominator))
+    }
+}
+
 impl Verify for ptype::DimensionInt {
-    type Verified = i32;
+    type Verified = i64;
 
     fn verify(self) -> Result<Self::Verified> {
         match self.0 {
@@ -105,28 +280,45 @@ impl Verify for ptype::DimensionInt {
 }
 
 impl Verify for ptype::Prefix {
-    type Verified = Prefix;
+    type Verified = Vec<Prefix>;
 
     fn verify(self) -> Result<Self::Verified> {
-        let name = match self {
-            ptype::Prefix::Ident(s) => Ok(s.to_string()),
+        let (name, span) = match self {
+            ptype::Prefix::Ident(s) => Ok((s.to_string(), s.span())),
             ptype::Prefix::Lit(s) => match s {
-                Lit::Str(s) => Ok(s.value()),
+                Lit::Str(s) => Ok((s.value(), s.span())),
                 _ => Err(Error::new(
                     s.span(),
                     "Unexpected literal, expected a str".to_string(),
                 )),
             },
         }?;
-        Ok(Prefix { name })
+        Prefix::from_name(&name)
+            .ok_or_else(|| Error::new(span, format!("Unknown prefix: \"{}\"", name)))
     }
 }
 
 impl Verify for ptype::Prefixes {
     type Verified = Vec<Prefix>;
 
+    /// Groups may overlap with each other and with individually
+    /// listed prefixes, so only the first occurrence of each prefix
+    /// is kept.
     fn verify(self) -> Result<Self::Verified> {
-        self.0.into_iter().map(|x| x.verify()).collect()
+        let mut prefixes: Vec<Prefix> = vec![];
+        for prefix in self
+            .0
+            .into_iter()
+            .collect::<Vec<_>>()
+            .verify()?
+            .into_iter()
+            .flatten()
+        {
+            if !prefixes.iter().any(|p| p.name == prefix.name) {
+                prefixes.push(prefix);
+            }
+        }
+        Ok(prefixes)
     }
 }
 
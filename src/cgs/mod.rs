@@ -0,0 +1,126 @@
+use crate::dimension;
+use crate::unit_system;
+use crate::Ratio;
+
+#[cfg(feature = "si")]
+mod si_conversion;
+
+#[cfg(feature = "si")]
+pub use si_conversion::{
+    capacitance_from_si, capacitance_to_si, charge_from_si, charge_to_si, current_from_si,
+    current_to_si, dimension_from_si, dimension_to_si, electric_field_from_si,
+    electric_field_to_si, from_si, magnetic_field_strength_from_si, magnetic_field_strength_to_si,
+    magnetic_flux_density_from_si, magnetic_flux_density_to_si, magnetic_flux_from_si,
+    magnetic_flux_to_si, resistance_from_si, resistance_to_si, to_si, voltage_from_si,
+    voltage_to_si,
+};
+
+/// The base dimensions of the Gaussian system. Electromagnetic quantities
+/// are expressed in terms of length, mass and time, which requires
+/// fractional exponents, so that for example the statcoulomb
+/// is `g^1/2 cm^3/2 s^-1`.
+#[dimension]
+pub struct Dimension {
+    pub length: Ratio,
+    pub time: Ratio,
+    pub mass: Ratio,
+    pub temperature: Ratio,
+    pub amount_of_substance: Ratio,
+    pub luminous_intensity: Ratio,
+}
+
+unit_system!(
+    Quantity,
+    Dimension: Ratio,
+    [
+        // Base quantities and units
+        def Dimensionless = {},
+        unit dimensionless = Dimensionless,
+        def Length = { length: 1 },
+        unit (centimeters, "cm") = Length,
+        unit (meters, "m", [kilo, milli, micro, nano]) = 100 * centimeters,
+        unit (angstroms, "Å") = 1e-8 * centimeters,
+        unit (astronomical_units, "au") = 1.495978707e13 * centimeters,
+        unit (parsecs, "pc", [kilo, mega, giga]) = (648000 / pi) * astronomical_units,
+        unit (light_years, "ly") = 2.99792458e10 * 365.25 * 86400 * centimeters,
+        def Time = { time: 1 },
+        unit (seconds, "s", [milli, micro, nano]) = Time,
+        unit (years, "yr", [kilo, mega, giga]) = 365.25 * 86400 * seconds,
+        def Mass = { mass: 1 },
+        unit (grams, "g", [kilo, milli, micro]) = Mass,
+        unit (solar_masses, "M_sun") = 1.98847e33 * grams,
+        def Temperature = { temperature: 1 },
+        unit (kelvin, "K") = Temperature,
+        def AmountOfSubstance = { amount_of_substance: 1 },
+        unit (moles, "mol") = AmountOfSubstance,
+        def LuminousIntensity = { luminous_intensity: 1 },
+        unit (candelas, "cd") = LuminousIntensity,
+
+        // Mechanical quantities
+        def Area = Length^2,
+        unit (square_centimeters, "cm^2") = centimeters^2,
+        def Volume = Length^3,
+        unit (cubic_centimeters, "cm^3") = centimeters^3,
+        def Velocity = Length / Time,
+        unit (centimeters_per_second, "cm/s") = centimeters / seconds,
+        unit (kilometers_per_second, "km/s") = kilometers / seconds,
+        def Acceleration = Velocity / Time,
+        unit (gals, "Gal") = centimeters / seconds^2,
+        def Density = Mass / Volume,
+        unit (grams_per_cubic_centimeter, "g/cm^3") = grams / cubic_centimeters,
+        def Force = Mass * Acceleration,
+        unit (dynes, "dyn") = grams * gals,
+        def Energy = Force * Length,
+        unit (ergs, "erg") = dynes * centimeters,
+        unit (electronvolts, "eV", [kilo, mega, giga]) = 1.602176634e-12 * ergs,
+        def Power = Energy / Time,
+        unit (ergs_per_second, "erg/s") = ergs / seconds,
+        unit (solar_luminosities, "L_sun") = 3.828e33 * ergs_per_second,
+        def Pressure = Force / Area,
+        unit (baryes, "Ba") = dynes / square_centimeters,
+        def DynamicViscosity = Pressure * Time,
+        unit (poises, "P", [centi]) = baryes * seconds,
+        def KinematicViscosity = Area / Time,
+        unit (stokes, "St", [centi]) = square_centimeters / seconds,
+
+        // Electromagnetic quantities in Gaussian units
+        def Charge = { mass: 1/2, length: 3/2, time: -1 },
+        unit (statcoulombs, "statC") = Charge,
+        def Current = Charge / Time,
+        unit (statamperes, "statA") = statcoulombs / seconds,
+        def Voltage = Energy / Charge,
+        unit (statvolts, "statV") = ergs / statcoulombs,
+        def ElectricField = Voltage / Length,
+        unit statvolts_per_centimeter = statvolts / centimeters,
+        def Resistance = Voltage / Current,
+        unit (statohms, "statΩ") = statvolts / statamperes,
+        // Capacitances have the dimension of a length in the Gaussian
+        // system, so the statfarad is given without a symbol, since
+        // lengths would otherwise be displayed in statfarads.
+        def Capacitance = Charge / Voltage,
+        unit statfarads = statcoulombs / statvolts,
+        def MagneticFluxDensity = Voltage / Length,
+        unit (gauss, "G", [milli, micro, nano]) = statvolts / centimeters,
+        def MagneticFieldStrength = MagneticFluxDensity,
+        unit (oersteds, "Oe") = gauss,
+        def MagneticFlux = MagneticFluxDensity * Area,
+        unit (maxwells, "Mx") = gauss * square_centimeters,
+
+        // Quantities needed for the constants
+        def HeatCapacity = Energy / Temperature,
+        def Action = Energy * Time,
+
+        // CODATA 2018 values of physical constants
+        constant SPEED_OF_LIGHT = 2.99792458e10 * centimeters_per_second,
+        constant PLANCK_CONSTANT = 6.62607015e-27 * ergs * seconds,
+        constant REDUCED_PLANCK_CONSTANT = 1.054571817e-27 * ergs * seconds,
+        constant BOLTZMANN_CONSTANT = 1.380649e-16 * ergs / kelvin,
+        constant AVOGADRO_CONSTANT = 6.02214076e23 / moles,
+        constant ELEMENTARY_CHARGE = 4.803204712570263e-10 * statcoulombs,
+        constant GRAVITATIONAL_CONSTANT = 6.67430e-8 * cubic_centimeters / (grams * seconds^2),
+        constant ELECTRON_MASS = 9.1093837015e-28 * grams,
+        constant PROTON_MASS = 1.67262192369e-24 * grams,
+        constant FINE_STRUCTURE_CONSTANT = 7.2973525693e-3 * dimensionless,
+        constant STEFAN_BOLTZMANN_CONSTANT = 5.670374419e-5 * ergs_per_second / (square_centimeters * kelvin^4),
+    ]
+);
@@ -0,0 +1,223 @@
+use super::{Dimension, Quantity};
+use crate::si;
+use crate::{Ratio, StorageType};
+
+/// The dimension of the CGS quantity corresponding to an SI quantity.
+/// Fails to compile for quantities with an electric current component,
+/// since these have different dimensions in the Gaussian system.
+/// Such quantities are converted via the dedicated functions
+/// such as [`charge_from_si`].
+pub const fn dimension_from_si(dimension: si::Dimension) -> Dimension {
+    if dimension.current != 0 {
+        panic!("Cannot convert electromagnetic quantities between SI and Gaussian units via from_si, use the dedicated conversion functions instead.");
+    }
+    Dimension {
+        length: Ratio::int(dimension.length as i64),
+        time: Ratio::int(dimension.time as i64),
+        mass: Ratio::int(dimension.mass as i64),
+        temperature: Ratio::int(dimension.temperature as i64),
+        amount_of_substance: Ratio::int(dimension.amount_of_substance as i64),
+        luminous_intensity: Ratio::int(dimension.luminous_intensity as i64),
+    }
+}
+
+/// The dimension of the SI quantity corresponding to a CGS quantity.
+/// Fails to compile for quantities with fractional exponents, such
+/// as the electromagnetic quantities of the Gaussian system, which
+/// are converted via the dedicated functions such as [`charge_to_si`].
+pub const fn dimension_to_si(dimension: Dimension) -> si::Dimension {
+    if !(dimension.length.is_int()
+        && dimension.time.is_int()
+        && dimension.mass.is_int()
+        && dimension.temperature.is_int()
+        && dimension.amount_of_substance.is_int()
+        && dimension.luminous_intensity.is_int())
+    {
+        panic!("Cannot convert quantities with fractional exponents to SI units.");
+    }
+    si::Dimension {
+        length: dimension.length.numerator() as i32,
+        time: dimension.time.numerator() as i32,
+        mass: dimension.mass.numerator() as i32,
+        temperature: dimension.temperature.numerator() as i32,
+        current: 0,
+        amount_of_substance: dimension.amount_of_substance.numerator() as i32,
+        luminous_intensity: dimension.luminous_intensity.numerator() as i32,
+    }
+}
+
+/// The value of the CGS base unit of a dimension in SI base units.
+/// Only length (cm vs. m) and mass (g vs. kg) differ between the two.
+fn cgs_unit_in_si(dimension: si::Dimension) -> f64 {
+    0.01f64.powi(dimension.length) * 0.001f64.powi(dimension.mass)
+}
+
+/// The value of the SI base unit of a dimension in CGS base units.
+fn si_unit_in_cgs(dimension: si::Dimension) -> f64 {
+    100.0f64.powi(dimension.length) * 1000.0f64.powi(dimension.mass)
+}
+
+/// Convert an SI quantity to the CGS quantity of the same dimension.
+pub fn from_si<S: StorageType, const D: si::Dimension>(
+    quantity: si::Quantity<S, D>,
+) -> Quantity<S, { dimension_from_si(D) }> {
+    Quantity(quantity.0.div_f64(cgs_unit_in_si(D)))
+}
+
+/// Convert a CGS quantity to the SI quantity of the same dimension.
+pub fn to_si<S: StorageType, const D: Dimension>(
+    quantity: Quantity<S, D>,
+) -> si::Quantity<S, { dimension_to_si(D) }> {
+    si::Quantity(quantity.0.div_f64(si_unit_in_cgs(dimension_to_si(D))))
+}
+
+/// The speed of light in m/s, which relates the electromagnetic
+/// units of the Gaussian system to those of the SI.
+const SPEED_OF_LIGHT: f64 = 2.99792458e8;
+
+/// Conversion functions between an electromagnetic quantity of the SI
+/// and the corresponding quantity of the Gaussian system. Since the
+/// dimensions of the two quantities are unrelated, they are given
+/// explicitly, along with the value of the Gaussian unit in SI units.
+macro_rules! impl_electromagnetic_conversion {
+    ($from_si: ident, $to_si: ident, $name: literal, $si_dimension: expr, $cgs_dimension: expr, $cgs_unit_in_si: expr) => {
+        #[doc = concat!("Convert an SI ", $name, " to the Gaussian ", $name, ".")]
+        pub fn $from_si<S: StorageType>(
+            quantity: si::Quantity<S, { $si_dimension }>,
+        ) -> Quantity<S, { $cgs_dimension }> {
+            Quantity(quantity.0.div_f64($cgs_unit_in_si))
+        }
+
+        #[doc = concat!("Convert a Gaussian ", $name, " to the SI ", $name, ".")]
+        pub fn $to_si<S: StorageType>(
+            quantity: Quantity<S, { $cgs_dimension }>,
+        ) -> si::Quantity<S, { $si_dimension }> {
+            si::Quantity(quantity.0.mul_f64($cgs_unit_in_si))
+        }
+    };
+}
+
+impl_electromagnetic_conversion!(
+    charge_from_si,
+    charge_to_si,
+    "charge",
+    si::Dimension::none().with_current(1).with_time(1),
+    Dimension::none()
+        .with_mass_ratio(1, 2)
+        .with_length_ratio(3, 2)
+        .with_time(-1),
+    1.0 / (10.0 * SPEED_OF_LIGHT)
+);
+
+impl_electromagnetic_conversion!(
+    current_from_si,
+    current_to_si,
+    "current",
+    si::Dimension::none().with_current(1),
+    Dimension::none()
+        .with_mass_ratio(1, 2)
+        .with_length_ratio(3, 2)
+        .with_time(-2),
+    1.0 / (10.0 * SPEED_OF_LIGHT)
+);
+
+impl_electromagnetic_conversion!(
+    voltage_from_si,
+    voltage_to_si,
+    "voltage",
+    si::Dimension::none()
+        .with_mass(1)
+        .with_length(2)
+        .with_time(-3)
+        .with_current(-1),
+    Dimension::none()
+        .with_mass_ratio(1, 2)
+        .with_length_ratio(1, 2)
+        .with_time(-1),
+    1e-6 * SPEED_OF_LIGHT
+);
+
+impl_electromagnetic_conversion!(
+    electric_field_from_si,
+    electric_field_to_si,
+    "electric field",
+    si::Dimension::none()
+        .with_mass(1)
+        .with_length(1)
+        .with_time(-3)
+        .with_current(-1),
+    Dimension::none()
+        .with_mass_ratio(1, 2)
+        .with_length_ratio(-1, 2)
+        .with_time(-1),
+    1e-4 * SPEED_OF_LIGHT
+);
+
+impl_electromagnetic_conversion!(
+    resistance_from_si,
+    resistance_to_si,
+    "resistance",
+    si::Dimension::none()
+        .with_mass(1)
+        .with_length(2)
+        .with_time(-3)
+        .with_current(-2),
+    Dimension::none().with_length(-1).with_time(1),
+    1e-5 * SPEED_OF_LIGHT * SPEED_OF_LIGHT
+);
+
+impl_electromagnetic_conversion!(
+    capacitance_from_si,
+    capacitance_to_si,
+    "capacitance",
+    si::Dimension::none()
+        .with_mass(-1)
+        .with_length(-2)
+        .with_time(4)
+        .with_current(2),
+    Dimension::none().with_length(1),
+    1e5 / (SPEED_OF_LIGHT * SPEED_OF_LIGHT)
+);
+
+impl_electromagnetic_conversion!(
+    magnetic_flux_density_from_si,
+    magnetic_flux_density_to_si,
+    "magnetic flux density",
+    si::Dimension::none()
+        .with_mass(1)
+        .with_time(-2)
+        .with_current(-1),
+    Dimension::none()
+        .with_mass_ratio(1, 2)
+        .with_length_ratio(-1, 2)
+        .with_time(-1),
+    1e-4
+);
+
+impl_electromagnetic_conversion!(
+    magnetic_flux_from_si,
+    magnetic_flux_to_si,
+    "magnetic flux",
+    si::Dimension::none()
+        .with_mass(1)
+        .with_length(2)
+        .with_time(-2)
+        .with_current(-1),
+    Dimension::none()
+        .with_mass_ratio(1, 2)
+        .with_length_ratio(3, 2)
+        .with_time(-1),
+    1e-8
+);
+
+impl_electromagnetic_conversion!(
+    magnetic_field_strength_from_si,
+    magnetic_field_strength_to_si,
+    "magnetic field strength",
+    si::Dimension::none().with_current(1).with_length(-1),
+    Dimension::none()
+        .with_mass_ratio(1, 2)
+        .with_length_ratio(-1, 2)
+        .with_time(-1),
+    1e3 / (4.0 * std::f64::consts::PI)
+);
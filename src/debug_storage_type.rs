@@ -1,38 +1,51 @@
+use crate::StorageType;
+
 /// A storage type that can be debug printed.
-pub trait DebugStorageType {
+pub trait DebugStorageType: StorageType {
     fn representative_value(&self) -> f64;
-    fn div_f64(&self, val: f64) -> Self;
+
+    /// Whether the value is an exact multiple of `val`, so that it
+    /// can be printed in a unit with this factor without rounding.
+    fn is_exact_multiple(&self, _val: f64) -> bool {
+        true
+    }
 }
 
 impl DebugStorageType for f32 {
     fn representative_value(&self) -> f64 {
         *self as f64
     }
-
-    fn div_f64(&self, val: f64) -> Self {
-        self / (val as f32)
-    }
 }
 
 impl DebugStorageType for f64 {
     fn representative_value(&self) -> f64 {
         *self
     }
+}
 
-    fn div_f64(&self, val: f64) -> Self {
-        self / val
-    }
+macro_rules! impl_debug_storage_type_for_int {
+    ($int: ty) => {
+        impl DebugStorageType for $int {
+            fn representative_value(&self) -> f64 {
+                *self as f64
+            }
+
+            fn is_exact_multiple(&self, val: f64) -> bool {
+                let quotient = *self as f64 / val;
+                (quotient - quotient.round()).abs() <= 1e-9 * quotient.abs().max(1.0)
+            }
+        }
+    };
 }
 
+impl_debug_storage_type_for_int!(i32);
+impl_debug_storage_type_for_int!(i64);
+
 #[cfg(feature = "glam-vec2")]
 impl DebugStorageType for glam::Vec2 {
     fn representative_value(&self) -> f64 {
         self.abs().max_element() as f64
     }
-
-    fn div_f64(&self, val: f64) -> Self {
-        *self / (val as f32)
-    }
 }
 
 #[cfg(feature = "glam-vec3")]
@@ -40,10 +53,6 @@ impl DebugStorageType for glam::Vec3 {
     fn representative_value(&self) -> f64 {
         self.abs().max_element() as f64
     }
-
-    fn div_f64(&self, val: f64) -> Self {
-        *self / (val as f32)
-    }
 }
 
 #[cfg(feature = "glam-dvec2")]
@@ -51,10 +60,6 @@ impl DebugStorageType for glam::DVec2 {
     fn representative_value(&self) -> f64 {
         self.abs().max_element()
     }
-
-    fn div_f64(&self, val: f64) -> Self {
-        *self / val
-    }
 }
 
 #[cfg(feature = "glam-dvec3")]
@@ -62,8 +67,75 @@ impl DebugStorageType for glam::DVec3 {
     fn representative_value(&self) -> f64 {
         self.abs().max_element()
     }
+}
+
+#[cfg(feature = "glam-vec4")]
+impl DebugStorageType for glam::Vec4 {
+    fn representative_value(&self) -> f64 {
+        self.abs().max_element() as f64
+    }
+}
 
-    fn div_f64(&self, val: f64) -> Self {
-        *self / val
+#[cfg(feature = "glam-dvec4")]
+impl DebugStorageType for glam::DVec4 {
+    fn representative_value(&self) -> f64 {
+        self.abs().max_element()
+    }
+}
+
+#[cfg(any(feature = "glam-ivec2", feature = "glam-ivec3"))]
+macro_rules! impl_debug_storage_type_for_int_vector {
+    ($vector: ty) => {
+        impl DebugStorageType for $vector {
+            fn representative_value(&self) -> f64 {
+                self.abs().max_element() as f64
+            }
+
+            fn is_exact_multiple(&self, val: f64) -> bool {
+                self.to_array()
+                    .iter()
+                    .all(|component| component.is_exact_multiple(val))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "glam-ivec2")]
+impl_debug_storage_type_for_int_vector!(glam::IVec2);
+#[cfg(feature = "glam-ivec3")]
+impl_debug_storage_type_for_int_vector!(glam::IVec3);
+
+#[cfg(feature = "num-complex")]
+impl DebugStorageType for num_complex::Complex32 {
+    fn representative_value(&self) -> f64 {
+        self.norm() as f64
+    }
+}
+
+#[cfg(feature = "num-complex")]
+impl DebugStorageType for num_complex::Complex64 {
+    fn representative_value(&self) -> f64 {
+        self.norm()
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl DebugStorageType for nalgebra::Vector3<f64> {
+    fn representative_value(&self) -> f64 {
+        self.amax()
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl DebugStorageType for nalgebra::Matrix3<f64> {
+    fn representative_value(&self) -> f64 {
+        self.amax()
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<Ix: ndarray::Dimension> DebugStorageType for ndarray::Array<f64, Ix> {
+    fn representative_value(&self) -> f64 {
+        self.iter().fold(0.0, |max, value| value.abs().max(max))
     }
 }
@@ -0,0 +1,65 @@
+use crate::Ratio;
+
+/// The types of the fields of dimension types. Used by
+/// `unit_system!` to check the exponents of its definitions
+/// against the fields of the dimension type.
+pub trait Exponent {
+    /// The type into which integer exponents have to fit.
+    type Int;
+}
+
+/// The field types which allow fractional exponents.
+#[diagnostic::on_unimplemented(
+    message = "Fractional exponents require fields of type diman::Ratio, found `{Self}`",
+    label = "the definition has a fractional exponent"
+)]
+pub trait FractionalExponent: Exponent {}
+
+impl Exponent for i8 {
+    type Int = i8;
+}
+
+impl Exponent for i32 {
+    type Int = i32;
+}
+
+impl Exponent for i64 {
+    type Int = i64;
+}
+
+impl Exponent for Ratio {
+    type Int = i64;
+}
+
+impl FractionalExponent for Ratio {}
+
+/// The exponent `N / D` as a value of the field type. Used by
+/// `unit_system!` to set the fields of the dimension type, whose
+/// types are not known to the macro. Exponents which do not fit
+/// into an integer field are reported by the checks of the
+/// definitions (see `Exponent`), so their value here is irrelevant.
+pub trait ExponentValue<const N: i64, const D: i64> {
+    const VALUE: Self;
+}
+
+impl<const N: i64, const D: i64> ExponentValue<N, D> for i8 {
+    const VALUE: i8 = (N / D) as i8;
+}
+
+impl<const N: i64, const D: i64> ExponentValue<N, D> for i32 {
+    const VALUE: i32 = (N / D) as i32;
+}
+
+impl<const N: i64, const D: i64> ExponentValue<N, D> for i64 {
+    const VALUE: i64 = N / D;
+}
+
+impl<const N: i64, const D: i64> ExponentValue<N, D> for Ratio {
+    const VALUE: Ratio = Ratio::new(N, D);
+}
+
+/// The exponent `N / D` as a value of the type `T` of a field,
+/// which is inferred from the field that is set.
+pub const fn exponent<T: ExponentValue<N, D>, const N: i64, const D: i64>() -> T {
+    T::VALUE
+}
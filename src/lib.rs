@@ -50,17 +50,41 @@ for _ in 0..100 {
 "#
 )]
 
+// The code generated by `unit_system!` refers to `diman`, also
+// within the modules of the unit systems which are defined here.
+extern crate self as diman;
+
 mod debug_storage_type;
+mod exponent;
+mod storage_type;
 mod type_aliases;
+pub mod unit_string;
 
 #[cfg(feature = "si")]
-/// Defines the proper dimensions for the SI system. The unit definitions
-/// are primarily used for the doctests now and not complete in any sense.
+/// The SI system of units, containing the seven base units, the 22 named
+/// derived units, the non-SI units accepted for use with the SI and the
+/// CODATA 2018 values of the fundamental physical constants.
 pub mod si;
 
+#[cfg(feature = "cgs")]
+/// The Gaussian system of units, based on centimeters, grams and seconds.
+/// This is the only variant of the CGS system which is provided. The
+/// electrostatic (ESU) and electromagnetic (EMU) variants share its
+/// mechanical units, but define the electromagnetic units differently.
+/// Quantities without an electromagnetic component can be converted
+/// from and to the SI system via [`cgs::from_si`] and [`cgs::to_si`].
+/// Electromagnetic quantities are converted via dedicated functions
+/// such as [`cgs::charge_from_si`] or [`cgs::magnetic_flux_density_to_si`].
+pub mod cgs;
+
 pub use debug_storage_type::DebugStorageType;
+pub use diman_lib::ratio::Ratio;
 pub use diman_unit_system::dimension;
 pub use diman_unit_system::unit_system;
+pub use diman_unit_system::unit_system_conversion;
+#[doc(hidden)]
+pub use exponent::{exponent, Exponent, ExponentValue, FractionalExponent};
+pub use storage_type::StorageType;
 pub use type_aliases::Product;
 pub use type_aliases::QProduct;
 pub use type_aliases::Quotient;
@@ -1,4 +1,3 @@
-use crate as diman;
 use crate::dimension;
 use crate::unit_system;
 
@@ -17,19 +16,133 @@ unit_system!(
     Quantity,
     Dimension,
     [
+        // Base quantities and units
         def Dimensionless = {},
         unit dimensionless = Dimensionless,
         def Length = { length: 1 },
-        unit (meters, "m") = Length,
-        unit (kilometers, "km") = 1000.0 * meters,
+        unit (meters, "m", [metric_prefixes]) = Length,
         def Time = { time: 1 },
-        unit (seconds, "s") = 1.0 * Time,
-        unit (hours, "h") = 3600 * seconds,
+        unit (seconds, "s", [metric_prefixes]) = Time,
+        def Mass = { mass: 1 },
+        unit (grams, "g", [metric_prefixes]) = 1e-3 * Mass,
+        def Temperature = { temperature: 1 },
+        unit (kelvin, "K", [milli, micro, nano]) = Temperature,
+        def Current = { current: 1 },
+        unit (amperes, "A", [kilo, milli, micro, nano, pico]) = Current,
+        def AmountOfSubstance = { amount_of_substance: 1 },
+        unit (moles, "mol", [kilo, milli, micro, nano, pico]) = AmountOfSubstance,
+        def LuminousIntensity = { luminous_intensity: 1 },
+        unit (candelas, "cd") = LuminousIntensity,
+
+        // Derived quantities and the named SI units
+        def Area = Length^2,
+        unit (square_meters, "m^2") = meters^2,
+        def Volume = Length^3,
+        unit (cubic_meters, "m^3") = meters^3,
         def Velocity = Length / Time,
         unit (meters_per_second, "m/s") = meters / seconds,
-        def Area = Length * Length,
-        unit (square_meters, "m^2") = meters * meters,
-        def Volume = Length * Length * Length,
-        unit (cubic_meters, "m^3") = meters * meters * meters,
+        def Acceleration = Velocity / Time,
+        unit (meters_per_second_squared, "m/s^2") = meters / seconds^2,
+        def WaveNumber = Dimensionless / Length,
+        def Density = Mass / Volume,
+        def Momentum = Mass * Velocity,
+        def Action = Energy * Time,
+        // Angles are dimensionless in the SI. The angle units are given
+        // without a symbol, since dimensionless quantities would otherwise
+        // be displayed in radians or degrees.
+        def Angle = Dimensionless,
+        unit radians = Dimensionless,
+        def SolidAngle = Dimensionless,
+        unit steradians = Dimensionless,
+        def Frequency = Dimensionless / Time,
+        unit (hertz, "Hz", [kilo, mega, giga, tera]) = 1 / seconds,
+        def Force = Mass * Acceleration,
+        unit (newtons, "N", [kilo, mega, milli, micro]) = kilograms * meters_per_second_squared,
+        def Pressure = Force / Area,
+        unit (pascals, "Pa", [hecto, kilo, mega, giga]) = newtons / square_meters,
+        def Energy = Force * Length,
+        unit (joules, "J", [kilo, mega, giga, milli, micro]) = newtons * meters,
+        def Power = Energy / Time,
+        unit (watts, "W", [kilo, mega, giga, tera, milli, micro]) = joules / seconds,
+        def Charge = Current * Time,
+        unit (coulombs, "C", [milli, micro, nano, pico]) = amperes * seconds,
+        def Voltage = Power / Current,
+        unit (volts, "V", [kilo, mega, milli, micro, nano]) = watts / amperes,
+        def Capacitance = Charge / Voltage,
+        unit (farads, "F", [milli, micro, nano, pico]) = coulombs / volts,
+        def Resistance = Voltage / Current,
+        unit (ohms, "Ω", [kilo, mega, giga, milli]) = volts / amperes,
+        def Conductance = Dimensionless / Resistance,
+        unit (siemens, "S", [milli, micro]) = 1 / ohms,
+        def MagneticFlux = Voltage * Time,
+        unit (webers, "Wb") = volts * seconds,
+        def MagneticFluxDensity = MagneticFlux / Area,
+        unit (teslas, "T", [milli, micro, nano]) = webers / square_meters,
+        def Inductance = MagneticFlux / Current,
+        unit (henries, "H", [milli, micro, nano]) = webers / amperes,
+        unit (celsius, "°C") = kelvin + 273.15,
+        def LuminousFlux = LuminousIntensity * SolidAngle,
+        unit (lumens, "lm") = candelas * steradians,
+        def Illuminance = LuminousFlux / Area,
+        unit (lux, "lx") = lumens / square_meters,
+        def Activity = Dimensionless / Time,
+        unit (becquerels, "Bq", [kilo, mega, giga]) = 1 / seconds,
+        def AbsorbedDose = Energy / Mass,
+        unit (grays, "Gy", [milli]) = joules / kilograms,
+        def EquivalentDose = Energy / Mass,
+        unit (sieverts, "Sv", [milli, micro]) = joules / kilograms,
+        def CatalyticActivity = AmountOfSubstance / Time,
+        unit (katals, "kat") = moles / seconds,
+
+        // Quantities needed for the constants
+        def HeatCapacity = Energy / Temperature,
+        def MolarHeatCapacity = HeatCapacity / AmountOfSubstance,
+        def MolarCharge = Charge / AmountOfSubstance,
+        def LuminousEfficacy = LuminousFlux / Power,
+        def Permittivity = Capacitance / Length,
+        def Permeability = Inductance / Length,
+
+        // Non-SI units accepted for use with the SI
+        unit (minutes, "min") = 60 * seconds,
+        unit (hours, "h") = 60 * minutes,
+        unit (days, "d") = 24 * hours,
+        unit (astronomical_units, "au") = 149597870700 * meters,
+        unit degrees = (pi / 180) * radians,
+        unit arcminutes = degrees / 60,
+        unit arcseconds = arcminutes / 60,
+        unit (hectares, "ha") = 1e4 * square_meters,
+        unit (liters, "L", [milli, micro]) = 1e-3 * cubic_meters,
+        unit (tonnes, "t") = 1e3 * kilograms,
+        unit (daltons, "Da") = 1.66053906660e-27 * kilograms,
+        unit (electronvolts, "eV", [kilo, mega, giga, tera]) = 1.602176634e-19 * joules,
+
+        // Defining constants of the SI
+        constant HYPERFINE_TRANSITION_FREQUENCY_OF_CS = 9192631770 * hertz,
+        constant SPEED_OF_LIGHT = 299792458 * meters_per_second,
+        constant PLANCK_CONSTANT = 6.62607015e-34 * joules * seconds,
+        constant ELEMENTARY_CHARGE = 1.602176634e-19 * coulombs,
+        constant BOLTZMANN_CONSTANT = 1.380649e-23 * joules / kelvin,
+        constant AVOGADRO_CONSTANT = 6.02214076e23 / moles,
+        constant LUMINOUS_EFFICACY = 683 * lumens / watts,
+
+        // CODATA 2018 values of physical constants
+        constant REDUCED_PLANCK_CONSTANT = 1.054571817e-34 * joules * seconds,
+        constant GRAVITATIONAL_CONSTANT = 6.67430e-11 * cubic_meters / (kilograms * seconds^2),
+        constant VACUUM_MAGNETIC_PERMEABILITY = 1.25663706212e-6 * henries / meters,
+        constant VACUUM_ELECTRIC_PERMITTIVITY = 8.8541878128e-12 * farads / meters,
+        constant FINE_STRUCTURE_CONSTANT = 7.2973525693e-3 * dimensionless,
+        constant RYDBERG_CONSTANT = 10973731.568160 / meters,
+        constant BOHR_RADIUS = 5.29177210903e-11 * meters,
+        constant ELECTRON_MASS = 9.1093837015e-31 * kilograms,
+        constant PROTON_MASS = 1.67262192369e-27 * kilograms,
+        constant NEUTRON_MASS = 1.67492749804e-27 * kilograms,
+        constant ATOMIC_MASS_CONSTANT = 1.66053906660e-27 * kilograms,
+        constant MOLAR_GAS_CONSTANT = 8.314462618 * joules / (moles * kelvin),
+        constant FARADAY_CONSTANT = 96485.33212 * coulombs / moles,
+        constant STEFAN_BOLTZMANN_CONSTANT = 5.670374419e-8 * watts / (square_meters * kelvin^4),
+
+        // Conventional values
+        constant STANDARD_ACCELERATION_OF_GRAVITY = 9.80665 * meters_per_second_squared,
+        constant STANDARD_ATMOSPHERE = 101325 * pascals,
     ]
 );
@@ -0,0 +1,247 @@
+/// A type which can store the values of quantities.
+/// The storage types of a unit system can be extended by
+/// implementing this trait and declaring the type in a
+/// `storage` entry of `unit_system!`, such as `storage f16 = half::f16`,
+/// which provides unit constructors and conversions for it.
+pub trait StorageType: Sized {
+    /// Multiply the value by the factor of a unit.
+    fn mul_f64(&self, val: f64) -> Self;
+
+    /// Divide the value by the factor of a unit.
+    fn div_f64(&self, val: f64) -> Self;
+
+    /// Add the offset of a unit to the value.
+    fn add_f64(&self, val: f64) -> Self;
+
+    /// Subtract the offset of a unit from the value.
+    fn sub_f64(&self, val: f64) -> Self;
+}
+
+macro_rules! impl_storage_type_for_float {
+    ($float: ty) => {
+        impl StorageType for $float {
+            fn mul_f64(&self, val: f64) -> Self {
+                self * (val as $float)
+            }
+
+            fn div_f64(&self, val: f64) -> Self {
+                self / (val as $float)
+            }
+
+            fn add_f64(&self, val: f64) -> Self {
+                self + (val as $float)
+            }
+
+            fn sub_f64(&self, val: f64) -> Self {
+                self - (val as $float)
+            }
+        }
+    };
+}
+
+/// The factor or offset as an integer, if it is one.
+fn exact_integer(val: f64) -> Option<i128> {
+    (val.fract() == 0.0 && val.abs() < 2f64.powi(63)).then_some(val as i128)
+}
+
+/// The integer whose inverse is `val`, up to the rounding
+/// error of the division, as for factors such as `0.001`.
+fn inverse_integer(val: f64) -> Option<i128> {
+    let inverse = 1.0 / val;
+    let rounded = inverse.round();
+    ((inverse - rounded).abs() <= 4.0 * f64::EPSILON * rounded.abs())
+        .then(|| exact_integer(rounded))
+        .flatten()
+        .filter(|inverse| *inverse != 0)
+}
+
+/// The quotient, rounded to the nearest integer, with ties
+/// rounded away from zero.
+fn div_round(numerator: i128, denominator: i128) -> i128 {
+    let quotient = numerator / denominator;
+    let remainder = numerator % denominator;
+    if 2 * remainder.abs() >= denominator.abs() {
+        quotient + numerator.signum() * denominator.signum()
+    } else {
+        quotient
+    }
+}
+
+fn to_int<I: TryFrom<i128>>(value: i128) -> I {
+    I::try_from(value)
+        .unwrap_or_else(|_| panic!("Overflow when applying the factor or offset of a unit."))
+}
+
+/// Integer factors and offsets are applied with integer arithmetic,
+/// so that the values are exact. Factors whose inverse is an integer
+/// are applied by an integer division. Results which are not integers
+/// are rounded to the nearest integer. Panics if the result does not
+/// fit into the type.
+macro_rules! impl_storage_type_for_int {
+    ($int: ty) => {
+        impl StorageType for $int {
+            fn mul_f64(&self, val: f64) -> Self {
+                let value = *self as i128;
+                if let Some(factor) = exact_integer(val) {
+                    to_int(value * factor)
+                } else if let Some(divisor) = inverse_integer(val) {
+                    to_int(div_round(value, divisor))
+                } else {
+                    (*self as f64 * val).round() as $int
+                }
+            }
+
+            fn div_f64(&self, val: f64) -> Self {
+                let value = *self as i128;
+                if let Some(divisor) = exact_integer(val).filter(|divisor| *divisor != 0) {
+                    to_int(div_round(value, divisor))
+                } else if let Some(factor) = inverse_integer(val) {
+                    to_int(value * factor)
+                } else {
+                    (*self as f64 / val).round() as $int
+                }
+            }
+
+            fn add_f64(&self, val: f64) -> Self {
+                match exact_integer(val) {
+                    Some(offset) => to_int(*self as i128 + offset),
+                    None => (*self as f64 + val).round() as $int,
+                }
+            }
+
+            fn sub_f64(&self, val: f64) -> Self {
+                match exact_integer(val) {
+                    Some(offset) => to_int(*self as i128 - offset),
+                    None => (*self as f64 - val).round() as $int,
+                }
+            }
+        }
+    };
+}
+
+/// For vector and complex types, whose values are combined
+/// with the factors via their scalar type.
+#[cfg(any(feature = "glam", feature = "num-complex"))]
+macro_rules! impl_storage_type_via_scalar {
+    ($vector: ty, $float: ty) => {
+        impl StorageType for $vector {
+            fn mul_f64(&self, val: f64) -> Self {
+                *self * (val as $float)
+            }
+
+            fn div_f64(&self, val: f64) -> Self {
+                *self / (val as $float)
+            }
+
+            fn add_f64(&self, val: f64) -> Self {
+                *self + (val as $float)
+            }
+
+            fn sub_f64(&self, val: f64) -> Self {
+                *self - (val as $float)
+            }
+        }
+    };
+}
+
+impl_storage_type_for_float!(f32);
+impl_storage_type_for_float!(f64);
+
+impl_storage_type_for_int!(i32);
+impl_storage_type_for_int!(i64);
+
+#[cfg(feature = "glam-vec2")]
+impl_storage_type_via_scalar!(glam::Vec2, f32);
+#[cfg(feature = "glam-vec3")]
+impl_storage_type_via_scalar!(glam::Vec3, f32);
+#[cfg(feature = "glam-dvec2")]
+impl_storage_type_via_scalar!(glam::DVec2, f64);
+#[cfg(feature = "glam-dvec3")]
+impl_storage_type_via_scalar!(glam::DVec3, f64);
+#[cfg(feature = "glam-vec4")]
+impl_storage_type_via_scalar!(glam::Vec4, f32);
+#[cfg(feature = "glam-dvec4")]
+impl_storage_type_via_scalar!(glam::DVec4, f64);
+
+/// The factors and offsets are applied to each component
+/// with the integer arithmetic of `i32`.
+#[cfg(any(feature = "glam-ivec2", feature = "glam-ivec3"))]
+macro_rules! impl_storage_type_for_int_vector {
+    ($vector: ty) => {
+        impl StorageType for $vector {
+            fn mul_f64(&self, val: f64) -> Self {
+                Self::from_array(self.to_array().map(|x| x.mul_f64(val)))
+            }
+
+            fn div_f64(&self, val: f64) -> Self {
+                Self::from_array(self.to_array().map(|x| x.div_f64(val)))
+            }
+
+            fn add_f64(&self, val: f64) -> Self {
+                Self::from_array(self.to_array().map(|x| x.add_f64(val)))
+            }
+
+            fn sub_f64(&self, val: f64) -> Self {
+                Self::from_array(self.to_array().map(|x| x.sub_f64(val)))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "glam-ivec2")]
+impl_storage_type_for_int_vector!(glam::IVec2);
+#[cfg(feature = "glam-ivec3")]
+impl_storage_type_for_int_vector!(glam::IVec3);
+
+#[cfg(feature = "num-complex")]
+impl_storage_type_via_scalar!(num_complex::Complex32, f32);
+#[cfg(feature = "num-complex")]
+impl_storage_type_via_scalar!(num_complex::Complex64, f64);
+
+/// The offsets are added to all entries of vectors and matrices.
+#[cfg(feature = "nalgebra")]
+macro_rules! impl_storage_type_for_nalgebra {
+    ($matrix: ty) => {
+        impl StorageType for $matrix {
+            fn mul_f64(&self, val: f64) -> Self {
+                self * val
+            }
+
+            fn div_f64(&self, val: f64) -> Self {
+                self / val
+            }
+
+            fn add_f64(&self, val: f64) -> Self {
+                self.add_scalar(val)
+            }
+
+            fn sub_f64(&self, val: f64) -> Self {
+                self.add_scalar(-val)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "nalgebra")]
+impl_storage_type_for_nalgebra!(nalgebra::Vector3<f64>);
+#[cfg(feature = "nalgebra")]
+impl_storage_type_for_nalgebra!(nalgebra::Matrix3<f64>);
+
+#[cfg(feature = "ndarray")]
+impl<Ix: ndarray::Dimension> StorageType for ndarray::Array<f64, Ix> {
+    fn mul_f64(&self, val: f64) -> Self {
+        self * val
+    }
+
+    fn div_f64(&self, val: f64) -> Self {
+        self / val
+    }
+
+    fn add_f64(&self, val: f64) -> Self {
+        self + val
+    }
+
+    fn sub_f64(&self, val: f64) -> Self {
+        self - val
+    }
+}
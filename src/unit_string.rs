@@ -0,0 +1,355 @@
+//! Parsing of unit strings such as `kg m^2/s^2`, `W/(m^2 K)` or `s⁻¹`.
+//! This is used by the code generated by `unit_system!`, which looks up the
+//! symbols of the parsed unit string in its list of units.
+//!
+//! The grammar is
+//! ```text
+//! expr     = term { ["*" | "·" | "/"] term }
+//! term     = atom [ "^" exponent | superscript ]
+//! atom     = symbol | "(" expr ")"
+//! exponent = int [ "/" int ] | "(" int [ "/" int ] ")"
+//! ```
+//! Terms which are not separated by an operator are multiplied.
+//! Since symbols never start with a digit, `m^1/2` unambiguously
+//! denotes the square root of `m`.
+//! All operators are left-associative and have the same precedence,
+//! so `J/kg K` is equivalent to `(J/kg) K`.
+
+/// A unit symbol raised to a rational power `numerator / denominator`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnitPower<'a> {
+    pub symbol: &'a str,
+    pub numerator: i32,
+    pub denominator: i32,
+    /// The (one-based) column of the symbol in the unit string.
+    pub column: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnitStringErrorKind {
+    /// A token appeared in a place where it is not allowed.
+    UnexpectedToken,
+    /// The string ended where another token was expected.
+    UnexpectedEnd,
+    /// A parenthesis was opened but never closed.
+    UnclosedParenthesis,
+    /// An exponent could not be parsed.
+    InvalidExponent,
+}
+
+/// The error returned when a unit string cannot be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnitStringError {
+    pub token: String,
+    /// The (one-based) column of the offending token in the unit string.
+    pub column: usize,
+    pub kind: UnitStringErrorKind,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TokenKind {
+    Symbol,
+    Number,
+    Superscript(i32),
+    Times,
+    Over,
+    Caret,
+    Open,
+    Close,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Token<'a> {
+    kind: TokenKind,
+    text: &'a str,
+    column: usize,
+}
+
+const SUPERSCRIPTS: [(char, char); 12] = [
+    ('⁰', '0'),
+    ('¹', '1'),
+    ('²', '2'),
+    ('³', '3'),
+    ('⁴', '4'),
+    ('⁵', '5'),
+    ('⁶', '6'),
+    ('⁷', '7'),
+    ('⁸', '8'),
+    ('⁹', '9'),
+    ('⁻', '-'),
+    ('⁺', '+'),
+];
+
+fn superscript_to_ascii(c: char) -> Option<char> {
+    SUPERSCRIPTS
+        .iter()
+        .find(|(superscript, _)| *superscript == c)
+        .map(|(_, ascii)| *ascii)
+}
+
+fn operator_kind(c: char) -> Option<TokenKind> {
+    match c {
+        '*' | '·' | '⋅' => Some(TokenKind::Times),
+        '/' => Some(TokenKind::Over),
+        '^' => Some(TokenKind::Caret),
+        '(' => Some(TokenKind::Open),
+        ')' => Some(TokenKind::Close),
+        _ => None,
+    }
+}
+
+fn is_number_char(c: char) -> bool {
+    c.is_ascii_digit() || c == '-' || c == '+'
+}
+
+fn is_symbol_char(c: char) -> bool {
+    !c.is_whitespace() && operator_kind(c).is_none() && superscript_to_ascii(c).is_none()
+}
+
+fn tokenize(s: &str) -> Result<Vec<Token<'_>>, UnitStringError> {
+    let mut tokens = vec![];
+    let mut chars = s.char_indices().enumerate().peekable();
+    while let Some((column, (start, c))) = chars.next() {
+        let column = column + 1;
+        let mut end_of_run = |condition: fn(char) -> bool| {
+            let mut end = start + c.len_utf8();
+            while let Some((_, (index, c))) = chars.peek() {
+                if !condition(*c) {
+                    break;
+                }
+                end = index + c.len_utf8();
+                chars.next();
+            }
+            end
+        };
+        let token = if c.is_whitespace() {
+            continue;
+        } else if let Some(kind) = operator_kind(c) {
+            Token {
+                kind,
+                text: &s[start..start + c.len_utf8()],
+                column,
+            }
+        } else if superscript_to_ascii(c).is_some() {
+            let end = end_of_run(|c| superscript_to_ascii(c).is_some());
+            let text = &s[start..end];
+            let exponent: String = text.chars().filter_map(superscript_to_ascii).collect();
+            let exponent = exponent.parse().map_err(|_| UnitStringError {
+                token: text.to_string(),
+                column,
+                kind: UnitStringErrorKind::InvalidExponent,
+            })?;
+            Token {
+                kind: TokenKind::Superscript(exponent),
+                text,
+                column,
+            }
+        } else if is_number_char(c) {
+            let end = end_of_run(is_number_char);
+            Token {
+                kind: TokenKind::Number,
+                text: &s[start..end],
+                column,
+            }
+        } else {
+            let end = end_of_run(is_symbol_char);
+            Token {
+                kind: TokenKind::Symbol,
+                text: &s[start..end],
+                column,
+            }
+        };
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+struct Parser<'a> {
+    tokens: Vec<Token<'a>>,
+    pos: usize,
+    end_column: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<Token<'a>> {
+        self.tokens.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Result<Token<'a>, UnitStringError> {
+        let token = self.peek().ok_or_else(|| self.unexpected_end())?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn unexpected_end(&self) -> UnitStringError {
+        UnitStringError {
+            token: String::new(),
+            column: self.end_column,
+            kind: UnitStringErrorKind::UnexpectedEnd,
+        }
+    }
+
+    fn error(token: Token, kind: UnitStringErrorKind) -> UnitStringError {
+        UnitStringError {
+            token: token.text.to_string(),
+            column: token.column,
+            kind,
+        }
+    }
+
+    fn expr(&mut self) -> Result<Vec<UnitPower<'a>>, UnitStringError> {
+        let mut powers = self.term()?;
+        while let Some(token) = self.peek() {
+            match token.kind {
+                TokenKind::Times => {
+                    self.pos += 1;
+                    powers.extend(self.term()?);
+                }
+                TokenKind::Over => {
+                    self.pos += 1;
+                    for power in self.term()? {
+                        let numerator = power.numerator.checked_neg().ok_or_else(|| {
+                            Self::error(token, UnitStringErrorKind::InvalidExponent)
+                        })?;
+                        powers.push(UnitPower { numerator, ..power });
+                    }
+                }
+                TokenKind::Symbol | TokenKind::Open => powers.extend(self.term()?),
+                _ => break,
+            }
+        }
+        Ok(powers)
+    }
+
+    fn term(&mut self) -> Result<Vec<UnitPower<'a>>, UnitStringError> {
+        let token = self.next()?;
+        let powers = match token.kind {
+            TokenKind::Symbol => vec![UnitPower {
+                symbol: token.text,
+                numerator: 1,
+                denominator: 1,
+                column: token.column,
+            }],
+            TokenKind::Open => {
+                let powers = self.expr()?;
+                match self.peek() {
+                    Some(close) if close.kind == TokenKind::Close => self.pos += 1,
+                    _ => return Err(Self::error(token, UnitStringErrorKind::UnclosedParenthesis)),
+                }
+                powers
+            }
+            _ => return Err(Self::error(token, UnitStringErrorKind::UnexpectedToken)),
+        };
+        let (numerator, denominator, exponent_token) = match self.peek().map(|token| token.kind) {
+            Some(TokenKind::Caret) => {
+                self.pos += 1;
+                let exponent_token = self.peek();
+                let (numerator, denominator) = self.exponent()?;
+                (numerator, denominator, exponent_token)
+            }
+            Some(TokenKind::Superscript(exponent)) => (exponent, 1, Some(self.next()?)),
+            _ => (1, 1, None),
+        };
+        powers
+            .into_iter()
+            .map(|power| {
+                // The product of two i32 always fits into an i64, but the
+                // reduced exponent may still be out of range for an i32.
+                let numerator = power.numerator as i64 * numerator as i64;
+                let denominator = power.denominator as i64 * denominator as i64;
+                let divisor = gcd(numerator, denominator) * denominator.signum();
+                let invalid_exponent = || {
+                    Self::error(
+                        exponent_token.unwrap_or(token),
+                        UnitStringErrorKind::InvalidExponent,
+                    )
+                };
+                Ok(UnitPower {
+                    numerator: i32::try_from(numerator / divisor)
+                        .map_err(|_| invalid_exponent())?,
+                    denominator: i32::try_from(denominator / divisor)
+                        .map_err(|_| invalid_exponent())?,
+                    ..power
+                })
+            })
+            .collect()
+    }
+
+    fn integer(&mut self) -> Result<i32, UnitStringError> {
+        let token = self.next()?;
+        match token.kind {
+            TokenKind::Number => token
+                .text
+                .parse()
+                .map_err(|_| Self::error(token, UnitStringErrorKind::InvalidExponent)),
+            _ => Err(Self::error(token, UnitStringErrorKind::InvalidExponent)),
+        }
+    }
+
+    fn denominator(&mut self) -> Result<i32, UnitStringError> {
+        let denominator_token = self.peek();
+        let denominator = self.integer()?;
+        if denominator == 0 {
+            return Err(Self::error(
+                denominator_token.unwrap(),
+                UnitStringErrorKind::InvalidExponent,
+            ));
+        }
+        Ok(denominator)
+    }
+
+    fn exponent(&mut self) -> Result<(i32, i32), UnitStringError> {
+        let token = self.peek().ok_or_else(|| self.unexpected_end())?;
+        if token.kind != TokenKind::Open {
+            let numerator = self.integer()?;
+            let is_fraction = self.peek().map(|token| token.kind) == Some(TokenKind::Over)
+                && self.tokens.get(self.pos + 1).map(|token| token.kind) == Some(TokenKind::Number);
+            if is_fraction {
+                self.pos += 1;
+                return Ok((numerator, self.denominator()?));
+            }
+            return Ok((numerator, 1));
+        }
+        self.pos += 1;
+        let numerator = self.integer()?;
+        let next = self.next()?;
+        let denominator = match next.kind {
+            TokenKind::Close => return Ok((numerator, 1)),
+            TokenKind::Over => self.denominator()?,
+            _ => return Err(Self::error(next, UnitStringErrorKind::InvalidExponent)),
+        };
+        match self.peek() {
+            Some(close) if close.kind == TokenKind::Close => self.pos += 1,
+            _ => return Err(Self::error(token, UnitStringErrorKind::UnclosedParenthesis)),
+        }
+        Ok((numerator, denominator))
+    }
+}
+
+/// Parse a unit string into a list of unit symbols raised to rational powers.
+/// The same symbol may appear multiple times in the result.
+/// An empty string results in an empty list.
+pub fn parse_unit_string(s: &str) -> Result<Vec<UnitPower<'_>>, UnitStringError> {
+    let tokens = tokenize(s)?;
+    if tokens.is_empty() {
+        return Ok(vec![]);
+    }
+    let mut parser = Parser {
+        tokens,
+        pos: 0,
+        end_column: s.chars().count() + 1,
+    };
+    let powers = parser.expr()?;
+    match parser.peek() {
+        Some(token) => Err(Parser::error(token, UnitStringErrorKind::UnexpectedToken)),
+        None => Ok(powers),
+    }
+}
@@ -0,0 +1,121 @@
+macro_rules! gen_tests_for_float {
+    ($float_name: ident) => {
+        mod $float_name {
+            use crate::example_system::$float_name::Temperature;
+            use crate::example_system::{Dimension, QuantityPoint, UnitParseErrorKind};
+
+            fn assert_temperature_is_close(x: Temperature, y: Temperature) {
+                let (x, y) = (x.value_unchecked(), y.value_unchecked());
+                assert!((x - y).abs() < 1e-4, "{} {}", x, y);
+            }
+
+            fn assert_point_is_close<const D: Dimension>(
+                x: QuantityPoint<$float_name, D>,
+                y: QuantityPoint<$float_name, D>,
+            ) {
+                let (x, y) = (
+                    x.absolute().value_unchecked(),
+                    y.absolute().value_unchecked(),
+                );
+                assert!((x - y).abs() < 1e-4, "{} {}", x, y);
+            }
+
+            #[test]
+            fn offset_constructors() {
+                assert_temperature_is_close(
+                    Temperature::celsius(0.0).absolute(),
+                    Temperature::kelvin(273.15),
+                );
+                assert_temperature_is_close(
+                    Temperature::celsius(-273.15).absolute(),
+                    Temperature::kelvin(0.0),
+                );
+                assert_point_is_close(Temperature::fahrenheit(32.0), Temperature::celsius(0.0));
+                assert_point_is_close(Temperature::fahrenheit(212.0), Temperature::celsius(100.0));
+            }
+
+            #[test]
+            fn difference_constructors() {
+                assert_temperature_is_close(
+                    Temperature::celsius_difference(10.0),
+                    Temperature::kelvin(10.0),
+                );
+                assert_temperature_is_close(
+                    Temperature::celsius(30.0) - Temperature::celsius(20.0),
+                    Temperature::celsius_difference(10.0),
+                );
+                assert_temperature_is_close(
+                    Temperature::fahrenheit_difference(9.0),
+                    Temperature::kelvin(5.0),
+                );
+            }
+
+            #[test]
+            fn point_arithmetic() {
+                let t = Temperature::celsius(20.0) + Temperature::celsius_difference(5.0);
+                assert_point_is_close(t, Temperature::celsius(25.0));
+                let t = Temperature::celsius(20.0) - Temperature::kelvin(30.0);
+                assert_point_is_close(t, Temperature::celsius(-10.0));
+                assert!(Temperature::celsius(20.0) < Temperature::fahrenheit(70.0));
+            }
+
+            #[test]
+            fn from_str() {
+                let t: Temperature = "20 °C".parse().unwrap();
+                assert_temperature_is_close(t, Temperature::celsius(20.0).absolute());
+                let t: Temperature = "-40 °F".parse().unwrap();
+                assert_temperature_is_close(t, Temperature::celsius(-40.0).absolute());
+                let t: Temperature = "300 K".parse().unwrap();
+                assert_temperature_is_close(t, Temperature::kelvin(300.0));
+            }
+
+            #[test]
+            fn from_str_affine_unit_in_expression() {
+                let err = "20 °C/s"
+                    .parse::<crate::example_system::DynQuantity<$float_name>>()
+                    .unwrap_err();
+                assert_eq!(err.kind, UnitParseErrorKind::NonLinearUnit);
+                assert_eq!(err.token, "°C");
+                assert_eq!(err.column, 4);
+            }
+
+            #[test]
+            fn display_as() {
+                let t = Temperature::celsius(20.0).absolute();
+                assert_eq!(format!("{:.1}", t.display_as("°C").unwrap()), "20.0 °C");
+                assert_eq!(format!("{:.1}", t.display_as("°F").unwrap()), "68.0 °F");
+                assert_eq!(format!("{:.2}", t.display_as("K").unwrap()), "293.15 K");
+            }
+
+            #[test]
+            fn display_in() {
+                let t = Temperature::celsius(100.0);
+                assert_eq!(format!("{:.1}", t.display_in_celsius()), "100.0 °C");
+                assert_eq!(format!("{:.1}", t.display_in_fahrenheit()), "212.0 °F");
+            }
+        }
+    };
+}
+
+#[cfg(feature = "f32")]
+gen_tests_for_float!(f32);
+
+#[cfg(feature = "f64")]
+gen_tests_for_float!(f64);
+
+#[cfg(feature = "f64")]
+#[test]
+fn offset_conversions() {
+    use crate::example_system::f64::Temperature;
+    use crate::example_system::QuantityPoint;
+    use crate::utils::assert_is_close_float_f64;
+    let t = QuantityPoint::from_absolute(Temperature::kelvin(300.0));
+    assert_is_close_float_f64(t.absolute().in_kelvin(), 300.0);
+    assert!((t.in_celsius() - 26.85).abs() < 1e-10);
+    assert!((t.in_fahrenheit() - 80.33).abs() < 1e-10);
+    // The difference of two points is a quantity, which
+    // is converted without the offset of the unit.
+    let difference = Temperature::celsius(30.0) - Temperature::celsius(20.0);
+    assert!((difference.in_celsius_difference() - 10.0).abs() < 1e-10);
+    assert!((difference.in_fahrenheit_difference() - 18.0).abs() < 1e-10);
+}
@@ -1,17 +1,47 @@
 #![allow(incomplete_features)]
 #![feature(generic_const_exprs, adt_const_params)]
-#![feature(const_fn_floating_point_arithmetic)]
 
 pub mod example_system;
 pub mod utils;
 
+mod affine_units;
+pub mod base_units;
+mod dyn_quantity;
 mod float;
+#[cfg(any(feature = "i32", feature = "i64"))]
+pub mod int_storage;
+pub mod integer_exponent_types;
+mod logarithmic_units;
+
+pub mod rational_dimensions;
+#[cfg(feature = "si")]
+mod si;
+
+#[cfg(feature = "cgs")]
+mod cgs;
 
 mod type_aliases;
 
+mod unit_parser;
+
+pub mod unit_expressions;
+
+pub mod unit_system_conversion;
+
+pub mod user_storage_types;
+
 #[cfg(feature = "glam")]
 mod glam;
 
+#[cfg(all(feature = "num-complex", feature = "si"))]
+mod complex;
+
+#[cfg(all(feature = "nalgebra", feature = "si"))]
+mod nalgebra_types;
+
+#[cfg(all(feature = "ndarray", feature = "si"))]
+mod arrays;
+
 #[cfg(feature = "mpi")]
 mod mpi;
 
@@ -35,3 +65,17 @@ fn compile_fail_glam() {
     let t = trybuild::TestCases::new();
     t.compile_fail("tests/compile_fail/glam_*.rs");
 }
+
+#[test]
+#[cfg(feature = "i32")]
+fn compile_fail_int() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/compile_fail/int_*.rs");
+}
+
+#[test]
+fn compile_fail_unit_system() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/compile_fail/unit_system_*.rs");
+    t.compile_fail("tests/compile_fail/dimension_*.rs");
+}
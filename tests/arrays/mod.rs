@@ -0,0 +1,71 @@
+use diman::si::array::{Area as AreaArray, Length, Temperature, Velocity};
+use diman::si::f64::{Area, Length as ScalarLength, Time, Velocity as ScalarVelocity};
+use ndarray::{array, Array1, Array2, Ix2};
+
+#[test]
+fn constructors_and_conversions() {
+    let lengths = Length::kilometers(array![1.0, 2.0]);
+    assert_eq!(lengths.clone().value_unchecked(), array![1000.0, 2000.0]);
+    assert_eq!(lengths.in_meters(), array![1000.0, 2000.0]);
+    let temperatures = Temperature::celsius(array![0.0, 100.0]);
+    assert_eq!(temperatures.absolute().in_kelvin(), array![273.15, 373.15]);
+    let matrix: Length<Ix2> = Length::meters(array![[1.0, 2.0], [3.0, 4.0]]);
+    assert_eq!(matrix.shape(), &[2, 2]);
+    assert_eq!(matrix.in_centimeters()[[1, 0]], 300.0);
+}
+
+#[test]
+fn reductions() {
+    let lengths = Length::meters(array![1.0, -4.0, 3.0]);
+    assert_eq!(lengths.sum(), ScalarLength::meters(0.0));
+    assert_eq!(lengths.mean(), Some(ScalarLength::meters(0.0)));
+    assert_eq!(lengths.max(), Some(ScalarLength::meters(3.0)));
+    assert_eq!(lengths.min(), Some(ScalarLength::meters(-4.0)));
+    assert_eq!(lengths.abs().max(), Some(ScalarLength::meters(4.0)));
+    let empty = Length::meters(Array1::zeros(0));
+    assert!(empty.is_empty());
+    assert_eq!(empty.mean(), None);
+    assert_eq!(empty.max(), None);
+}
+
+#[test]
+fn indexing() {
+    let mut lengths = Length::meters(array![1.0, 2.0, 3.0]);
+    assert_eq!(lengths.len(), 3);
+    assert_eq!(lengths[1], ScalarLength::meters(2.0));
+    assert_eq!(lengths.get(3), None);
+    lengths[1] = ScalarLength::kilometers(1.0);
+    assert_eq!(lengths.get(1), Some(ScalarLength::meters(1000.0)));
+    let matrix = Length::<Ix2>::meters(Array2::eye(2));
+    assert_eq!(matrix[[0, 0]], ScalarLength::meters(1.0));
+    assert_eq!(matrix[[0, 1]], ScalarLength::meters(0.0));
+}
+
+#[test]
+fn elementwise_arithmetic() {
+    let x = Length::meters(array![1.0, 2.0]);
+    let y = Length::meters(array![3.0, 4.0]);
+    assert_eq!(x.clone() + y.clone(), Length::meters(array![4.0, 6.0]));
+    assert_eq!(y.clone() - x.clone(), Length::meters(array![2.0, 2.0]));
+    let area: AreaArray = x.clone() * y;
+    assert_eq!(area.value_unchecked(), array![3.0, 8.0]);
+    let scaled: AreaArray = x.clone() * ScalarLength::meters(2.0);
+    assert_eq!(scaled.value_unchecked(), array![2.0, 4.0]);
+    let velocities: Velocity = x.clone() / Time::seconds(2.0);
+    assert_eq!(velocities.in_meters_per_second(), array![0.5, 1.0]);
+    assert_eq!(x.clone() * 2.0, Length::meters(array![2.0, 4.0]));
+    assert_eq!(x.clone() / 2.0, Length::meters(array![0.5, 1.0]));
+    let total: Area = (x.clone() * x).sum();
+    assert_eq!(total, Area::square_meters(5.0));
+}
+
+#[test]
+fn collect_from_scalars() {
+    let lengths: Length = (1..=3).map(|i| ScalarLength::meters(i as f64)).collect();
+    assert_eq!(lengths, Length::meters(array![1.0, 2.0, 3.0]));
+    let times: Vec<_> = lengths
+        .iter()
+        .map(|length| length / ScalarVelocity::meters_per_second(1.0))
+        .collect();
+    assert_eq!(times[2], Time::seconds(3.0));
+}
@@ -0,0 +1,113 @@
+use ::diman::dimension;
+use ::diman::unit_system;
+
+#[dimension]
+pub struct Dimension {
+    pub length: i32,
+    pub time: i32,
+    pub mass: i32,
+}
+
+// Typical units of a galaxy simulation. Quantities are stored in
+// kiloparsecs, 1e10 solar masses and kiloparsecs per (km/s).
+unit_system!(
+    Quantity,
+    Dimension,
+    [
+        def Dimensionless = {},
+        unit dimensionless = Dimensionless,
+        def Length = { length: 1 },
+        unit (meters, "m", [kilo]) = Length,
+        unit (parsecs, "pc", [kilo, mega]) = 3.0856775814913673e16 * meters,
+        def Time = { time: 1 },
+        unit (seconds, "s") = Time,
+        unit (years, "yr", [mega, giga]) = 3.15576e7 * seconds,
+        unit code_times = kiloparsecs / kilometers_per_second,
+        def Mass = { mass: 1 },
+        unit (kilograms, "kg") = Mass,
+        unit (solar_masses, "Msun") = 1.98847e30 * kilograms,
+        unit (code_masses, "1e10 Msun") = 1e10 * solar_masses,
+        def Velocity = Length / Time,
+        unit (meters_per_second, "m/s") = meters / seconds,
+        unit (kilometers_per_second, "km/s") = kilometers / seconds,
+        def Acceleration = Velocity / Time,
+        def Force = Mass * Acceleration,
+        unit (newtons, "N") = kilograms * meters / (seconds * seconds),
+        constant GRAVITATIONAL_CONSTANT = 6.67430e-11 * meters * meters * meters / (kilograms * seconds * seconds),
+        base length = kiloparsecs,
+        base mass = code_masses,
+        base time = code_times,
+    ]
+);
+
+macro_rules! gen_tests_for_float {
+    ($float_name: ident) => {
+        mod $float_name {
+            use super::super::$float_name::Length;
+            use super::super::$float_name::Mass;
+            use super::super::$float_name::Time;
+            use super::super::$float_name::Velocity;
+            use super::super::$float_name::GRAVITATIONAL_CONSTANT;
+
+            fn assert_is_close_relative(x: $float_name, y: $float_name) {
+                assert!(((x - y) / y).abs() < 1e-5, "{} {}", x, y);
+            }
+
+            #[test]
+            fn values_are_stored_in_base_units() {
+                assert_eq!(Length::kiloparsecs(2.0).value_unchecked(), 2.0);
+                assert_eq!(Mass::code_masses(2.0).value_unchecked(), 2.0);
+                assert_eq!(Time::code_times(2.0).value_unchecked(), 2.0);
+                assert_is_close_relative(
+                    Velocity::kilometers_per_second(2.0).value_unchecked(),
+                    2.0,
+                );
+            }
+
+            #[test]
+            fn conversions() {
+                assert_is_close_relative(Length::megaparsecs(1.0).value_unchecked(), 1000.0);
+                assert_is_close_relative(Length::meters(3.0856776e19).value_unchecked(), 1.0);
+                assert_is_close_relative(Mass::solar_masses(1e10).value_unchecked(), 1.0);
+                assert_is_close_relative(Time::megayears(977.79).value_unchecked(), 1.0);
+            }
+
+            #[test]
+            fn constants() {
+                assert_is_close_relative(GRAVITATIONAL_CONSTANT.value_unchecked(), 43010.47);
+            }
+
+            #[test]
+            fn debug() {
+                assert_eq!(format!("{:?}", Length::kiloparsecs(2.0)), "2 kpc");
+            }
+
+            #[test]
+            fn from_str() {
+                let x: Length = "3 kpc".parse().unwrap();
+                assert_is_close_relative(x.value_unchecked(), 3.0);
+                let x: Length = "3 Mpc".parse().unwrap();
+                assert_is_close_relative(x.value_unchecked(), 3000.0);
+            }
+        }
+    };
+}
+
+mod tests {
+    #[cfg(feature = "f32")]
+    gen_tests_for_float!(f32);
+
+    #[cfg(feature = "f64")]
+    gen_tests_for_float!(f64);
+
+    #[cfg(feature = "f64")]
+    #[test]
+    fn conversion_methods() {
+        use super::f64::{Length, Time};
+        let x = Length::kiloparsecs(1.0);
+        assert!((x.in_meters() / 3.085677581491367e19 - 1.0).abs() < 1e-12);
+        assert!((x.in_megaparsecs() - 1e-3).abs() < 1e-12);
+        let velocity = Length::kiloparsecs(1.0) / Time::gigayears(1.0);
+        assert!((velocity.in_kilometers_per_second() - 0.97779).abs() < 1e-5);
+    }
+}
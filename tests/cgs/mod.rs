@@ -0,0 +1,269 @@
+macro_rules! gen_tests_for_float {
+    ($float_name: ident) => {
+        mod $float_name {
+            use diman::cgs::$float_name::*;
+            use diman::cgs::Dimension;
+            use diman::cgs::DynQuantity;
+            use diman::cgs::Quantity;
+            use diman::Ratio;
+
+            fn assert_is_close<const D: Dimension>(
+                x: Quantity<$float_name, D>,
+                y: Quantity<$float_name, D>,
+            ) {
+                let (x, y) = (x.value_unchecked(), y.value_unchecked());
+                assert!(((x - y) / y).abs() < 1e-6, "{} {}", x, y);
+            }
+
+            #[test]
+            fn mechanical_units() {
+                assert_is_close(Length::meters(1.0), Length::centimeters(100.0));
+                assert_is_close(Mass::kilograms(1.0), Mass::grams(1000.0));
+                assert_is_close(
+                    Force::dynes(1.0),
+                    Mass::grams(1.0) * Length::centimeters(1.0)
+                        / (Time::seconds(1.0) * Time::seconds(1.0)),
+                );
+                assert_is_close(
+                    Energy::ergs(1.0),
+                    Force::dynes(1.0) * Length::centimeters(1.0),
+                );
+                assert_is_close(
+                    Pressure::baryes(1.0),
+                    Force::dynes(1.0) / Area::square_centimeters(1.0),
+                );
+                assert_is_close(
+                    DynamicViscosity::centipoises(100.0),
+                    Pressure::baryes(1.0) * Time::seconds(1.0),
+                );
+                assert_is_close(
+                    KinematicViscosity::stokes(1.0),
+                    Area::square_centimeters(1.0) / Time::seconds(1.0),
+                );
+                assert_is_close(
+                    Velocity::kilometers_per_second(1.0),
+                    Velocity::centimeters_per_second(1e5),
+                );
+            }
+
+            #[test]
+            fn astronomical_units() {
+                assert_is_close(Length::parsecs(1.0), Length::astronomical_units(206264.8));
+                assert_is_close(Length::kiloparsecs(1.0), Length::parsecs(1000.0));
+                assert_is_close(Time::megayears(1.0), Time::seconds(3.15576e13));
+            }
+
+            #[test]
+            fn gaussian_charge() {
+                // Two charges of one statcoulomb at a distance of one
+                // centimeter repel each other with a force of one dyne.
+                let charge = Charge::statcoulombs(1.0);
+                let distance = Length::centimeters(1.0);
+                let force: Force = charge * charge / (distance * distance);
+                assert_is_close(force, Force::dynes(1.0));
+            }
+
+            #[test]
+            fn gaussian_dimensions() {
+                let dimension = DynQuantity::from(Charge::statcoulombs(1.0))
+                    .dimension()
+                    .clone();
+                assert_eq!(
+                    dimension,
+                    Dimension {
+                        length: Ratio::new(3, 2),
+                        time: Ratio::int(-1),
+                        mass: Ratio::new(1, 2),
+                        temperature: Ratio::int(0),
+                        amount_of_substance: Ratio::int(0),
+                        luminous_intensity: Ratio::int(0),
+                    }
+                );
+                assert_is_close(
+                    Voltage::statvolts(1.0) * Charge::statcoulombs(1.0),
+                    Energy::ergs(1.0),
+                );
+                assert_is_close(
+                    Current::statamperes(1.0),
+                    Charge::statcoulombs(1.0) / Time::seconds(1.0),
+                );
+                assert_is_close(
+                    Resistance::statohms(1.0),
+                    Voltage::statvolts(1.0) / Current::statamperes(1.0),
+                );
+                assert_is_close(Capacitance::statfarads(1.0), Length::centimeters(1.0));
+            }
+
+            #[test]
+            fn magnetic_units() {
+                assert_is_close(
+                    MagneticFlux::maxwells(1.0),
+                    MagneticFluxDensity::gauss(1.0) * Area::square_centimeters(1.0),
+                );
+                assert_is_close(
+                    MagneticFieldStrength::oersteds(1.0),
+                    MagneticFluxDensity::gauss(1.0),
+                );
+                assert_is_close(
+                    MagneticFluxDensity::microgauss(1.0),
+                    MagneticFluxDensity::gauss(1e-6),
+                );
+                // The square of a magnetic field is an energy density.
+                let field = MagneticFluxDensity::gauss(1.0);
+                let energy_density = field * field;
+                assert_is_close(
+                    energy_density,
+                    Energy::ergs(1.0) / Volume::cubic_centimeters(1.0),
+                );
+            }
+
+            #[test]
+            fn debug_symbols() {
+                assert_eq!(format!("{:?}", Force::dynes(2.0)), "2 dyn");
+                assert_eq!(format!("{:?}", Energy::ergs(2.0)), "2 erg");
+                assert_eq!(format!("{:?}", Charge::statcoulombs(2.0)), "2 statC");
+                assert_eq!(format!("{:?}", MagneticFluxDensity::gauss(2.0)), "2 G");
+                assert_eq!(format!("{:?}", Length::centimeters(2.0)), "2 cm");
+            }
+
+            #[test]
+            fn from_str() {
+                let x: Energy = "3 erg".parse().unwrap();
+                assert_is_close(x, Energy::ergs(3.0));
+                let x: MagneticFluxDensity = "2 µG".parse().unwrap();
+                assert_is_close(x, MagneticFluxDensity::microgauss(2.0));
+                let x: Velocity = "200 km/s".parse().unwrap();
+                assert_is_close(x, Velocity::centimeters_per_second(2e7));
+            }
+        }
+    };
+}
+
+#[cfg(feature = "f32")]
+gen_tests_for_float!(f32);
+
+#[cfg(feature = "f64")]
+gen_tests_for_float!(f64);
+
+#[cfg(feature = "f64")]
+mod constants {
+    use diman::cgs::f64::*;
+    use std::f64::consts::PI;
+
+    fn assert_is_close_relative(x: f64, y: f64) {
+        assert!(((x - y) / y).abs() < 1e-9, "{} {}", x, y);
+    }
+
+    #[test]
+    fn fine_structure_constant() {
+        let alpha: Dimensionless =
+            ELEMENTARY_CHARGE * ELEMENTARY_CHARGE / (REDUCED_PLANCK_CONSTANT * SPEED_OF_LIGHT);
+        assert_is_close_relative(
+            alpha.value_unchecked(),
+            FINE_STRUCTURE_CONSTANT.value_unchecked(),
+        );
+    }
+
+    #[test]
+    fn reduced_planck_constant() {
+        assert_is_close_relative(
+            (PLANCK_CONSTANT / (2.0 * PI)).value_unchecked(),
+            REDUCED_PLANCK_CONSTANT.value_unchecked(),
+        );
+    }
+}
+
+#[cfg(all(feature = "si", feature = "f64"))]
+mod si_conversion {
+    use diman::cgs;
+    use diman::si;
+
+    fn assert_is_close_relative(x: f64, y: f64) {
+        assert!(((x - y) / y).abs() < 1e-12, "{} {}", x, y);
+    }
+
+    #[test]
+    fn from_si() {
+        let length = cgs::from_si(si::f64::Length::meters(1.0));
+        assert_is_close_relative(length.in_centimeters(), 100.0);
+        let energy = cgs::from_si(si::f64::Energy::joules(1.0));
+        assert_is_close_relative(energy.in_ergs(), 1e7);
+        let force = cgs::from_si(si::f64::Force::newtons(1.0));
+        assert_is_close_relative(force.in_dynes(), 1e5);
+        let pressure = cgs::from_si(si::f64::Pressure::pascals(1.0));
+        assert_is_close_relative(pressure.in_baryes(), 10.0);
+        let gravitational_constant = cgs::from_si(si::f64::GRAVITATIONAL_CONSTANT);
+        assert_is_close_relative(
+            gravitational_constant.value_unchecked(),
+            cgs::f64::GRAVITATIONAL_CONSTANT.value_unchecked(),
+        );
+    }
+
+    #[test]
+    fn to_si() {
+        let energy = cgs::to_si(cgs::f64::Energy::ergs(1.0));
+        assert_is_close_relative(energy.in_joules(), 1e-7);
+        let density = cgs::to_si(cgs::f64::Density::grams_per_cubic_centimeter(1.0));
+        assert_is_close_relative(density.value_unchecked(), 1000.0);
+        let temperature = cgs::to_si(cgs::f64::Temperature::kelvin(300.0));
+        assert_is_close_relative(temperature.in_kelvin(), 300.0);
+        let boltzmann_constant = cgs::to_si(cgs::f64::BOLTZMANN_CONSTANT);
+        assert_is_close_relative(
+            boltzmann_constant.value_unchecked(),
+            si::f64::BOLTZMANN_CONSTANT.value_unchecked(),
+        );
+    }
+
+    #[test]
+    fn electromagnetic_from_si() {
+        let charge = cgs::charge_from_si(si::f64::Charge::coulombs(1.0));
+        assert_is_close_relative(charge.in_statcoulombs(), 2.99792458e9);
+        let elementary_charge = cgs::charge_from_si(si::f64::ELEMENTARY_CHARGE);
+        assert_is_close_relative(
+            elementary_charge.value_unchecked(),
+            cgs::f64::ELEMENTARY_CHARGE.value_unchecked(),
+        );
+        let current = cgs::current_from_si(si::f64::Current::amperes(1.0));
+        assert_is_close_relative(current.in_statamperes(), 2.99792458e9);
+        let field = cgs::magnetic_flux_density_from_si(si::f64::MagneticFluxDensity::teslas(1.0));
+        assert_is_close_relative(field.in_gauss(), 1e4);
+        let flux = cgs::magnetic_flux_from_si(si::f64::MagneticFlux::webers(1.0));
+        assert_is_close_relative(flux.in_maxwells(), 1e8);
+        let strength = cgs::magnetic_field_strength_from_si(
+            si::f64::Current::amperes(1e3) / si::f64::Length::meters(4.0 * std::f64::consts::PI),
+        );
+        assert_is_close_relative(strength.in_oersteds(), 1.0);
+    }
+
+    #[test]
+    fn electromagnetic_to_si() {
+        let voltage = cgs::voltage_to_si(cgs::f64::Voltage::statvolts(1.0));
+        assert_is_close_relative(voltage.in_volts(), 299.792458);
+        let field =
+            cgs::electric_field_to_si(cgs::f64::ElectricField::statvolts_per_centimeter(1.0));
+        assert_is_close_relative(field.value_unchecked(), 29979.2458);
+        let resistance = cgs::resistance_to_si(cgs::f64::Resistance::statohms(1.0));
+        assert_is_close_relative(resistance.in_ohms(), 8.987551787368176e11);
+        let capacitance = cgs::capacitance_to_si(cgs::f64::Capacitance::statfarads(1.0));
+        assert_is_close_relative(capacitance.in_farads(), 1.1126500560536185e-12);
+        let field = cgs::magnetic_flux_density_to_si(cgs::f64::MagneticFluxDensity::gauss(1.0));
+        assert_is_close_relative(field.in_teslas(), 1e-4);
+    }
+
+    #[test]
+    fn electromagnetic_round_trip() {
+        let x = si::f64::Voltage::volts(3.0);
+        assert_is_close_relative(cgs::voltage_to_si(cgs::voltage_from_si(x)).in_volts(), 3.0);
+        let x = si::f64::Capacitance::farads(2.0);
+        assert_is_close_relative(
+            cgs::capacitance_to_si(cgs::capacitance_from_si(x)).in_farads(),
+            2.0,
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let x = si::f64::Velocity::meters_per_second(3.0);
+        assert_is_close_relative(cgs::to_si(cgs::from_si(x)).value_unchecked(), 3.0);
+    }
+}
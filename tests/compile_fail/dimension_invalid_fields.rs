@@ -0,0 +1,14 @@
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs, adt_const_params)]
+use ::diman::dimension;
+
+#[dimension]
+pub struct Dimension {
+    pub length: i32,
+    pub time: f64,
+    pub mass: u8,
+    pub current: num_rational::Ratio<i64>,
+    pub temperature: Ratio<i32>,
+}
+
+fn main() {}
@@ -31,6 +31,9 @@ unit_system!(
         def Volume = Length * Length * Length,
         def Force = Energy / Length,
         unit (newtons, "N") = joules / meters,
+        def Temperature = { temperature: 1 },
+        unit (kelvin, "K") = Temperature,
+        unit (celsius, "°C") = kelvin + 273.15,
         constant SOLAR_MASS = 1.988477e30 * kilograms,
         constant SOLAR_MASS_GRAMS = 1.988477e33 * grams,
         constant SOLAR_MASS_AWKWARD = 1.988477e30 * kilograms / (seconds / seconds),
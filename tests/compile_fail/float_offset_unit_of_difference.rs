@@ -0,0 +1,9 @@
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs, adt_const_params)]
+pub mod example_system;
+
+fn main() {
+    use example_system::f64::Temperature;
+    let difference: Temperature = Temperature::celsius(30.0) - Temperature::celsius(20.0);
+    difference.in_celsius();
+}
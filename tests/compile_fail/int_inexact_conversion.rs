@@ -0,0 +1,8 @@
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs, adt_const_params)]
+pub mod example_system;
+
+fn main() {
+    use example_system::i32::Length;
+    Length::meters(1500).in_kilometers();
+}
@@ -0,0 +1,31 @@
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs, adt_const_params)]
+use ::diman::dimension;
+use ::diman::unit_system;
+
+#[dimension]
+pub struct Dimension {
+    pub length: i32,
+    pub time: i32,
+    pub mass: i32,
+}
+
+unit_system!(
+    Quantity,
+    Dimension,
+    [
+        def Length = { length: 1 },
+        unit (meters, "m") = Length,
+        def Time = { time: 1 },
+        unit (seconds, "s") = Time,
+        def Mass = { mass: 1 },
+        unit (kilograms, "kg") = Mass,
+        def Force = Mass * Length / (Time * Time),
+        unit (newtons, "N", [kilo, mega]): Force = kilograms * meters / seconds,
+        unit (dynes, "dyn"): Forse = 1e-5 * newtons,
+        constant EARTH_MASS: Mass = 5.972e24 * kilograms,
+        constant GRAVITY: Length = 9.81 * meters / (seconds * seconds),
+    ]
+);
+
+fn main() {}
@@ -0,0 +1,29 @@
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs, adt_const_params)]
+use ::diman::dimension;
+use ::diman::unit_system;
+
+#[dimension]
+pub struct Dimension {
+    pub length: i32,
+    pub time: i32,
+}
+
+unit_system!(
+    Quantity,
+    Dimension,
+    [
+        def Length = { length: 1 },
+        def Time = { time: 1 },
+        def Velocity = { length: 1, time: -1 },
+        def Length = Velocity * Time,
+        unit (meters, "m", [kilo]) = Length,
+        unit (kilometers, "km") = 1000.0 * meters,
+        unit (miles, "m") = 1609.344 * meters,
+        unit (seconds, "s") = Time,
+        base length = meters,
+        base length = kilometers,
+    ]
+);
+
+fn main() {}
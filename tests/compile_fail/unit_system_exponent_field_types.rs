@@ -0,0 +1,26 @@
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs, adt_const_params)]
+use ::diman::dimension;
+use ::diman::unit_system;
+
+#[dimension]
+pub struct Dimension {
+    pub length: i8,
+    pub time: i32,
+}
+
+unit_system!(
+    Quantity,
+    Dimension,
+    [
+        def Length = { length: 1 },
+        unit (meters, "m") = Length,
+        def Time = { time: 1 },
+        unit (seconds, "s") = Time,
+        def Big = Length^200,
+        def Small = Length^-128,
+        unit (per_cubic_meter_per_second, "m^-3 s^-1") = meters^-3 / seconds,
+    ]
+);
+
+fn main() {}
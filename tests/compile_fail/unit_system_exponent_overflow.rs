@@ -0,0 +1,24 @@
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs, adt_const_params)]
+use ::diman::dimension;
+use ::diman::unit_system;
+
+#[dimension]
+pub struct Dimension {
+    pub length: i64,
+}
+
+unit_system!(
+    Quantity,
+    Dimension: i64,
+    [
+        def Length = { length: 1 },
+        unit (meters, "m") = Length,
+        def Huge = { length: 4611686018427387904 },
+        def Huger = Huge^4,
+        def Hugest = Huger * Length,
+        def Overflowing = Huge * Huge * Length,
+    ]
+);
+
+fn main() {}
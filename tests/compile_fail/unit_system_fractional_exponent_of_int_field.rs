@@ -0,0 +1,24 @@
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs, adt_const_params)]
+use ::diman::dimension;
+use ::diman::unit_system;
+
+#[dimension]
+pub struct Dimension {
+    pub length: i32,
+    pub time: diman::Ratio,
+}
+
+unit_system!(
+    Quantity,
+    Dimension,
+    [
+        def Length = { length: 1 },
+        unit (meters, "m") = Length,
+        def Time = { time: 1 },
+        def RootTime = { time: 1/2 },
+        def RootLength = { length: 1/2 },
+    ]
+);
+
+fn main() {}
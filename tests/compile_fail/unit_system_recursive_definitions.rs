@@ -0,0 +1,29 @@
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs, adt_const_params)]
+use ::diman::dimension;
+use ::diman::unit_system;
+
+#[dimension]
+pub struct Dimension {
+    pub length: i32,
+    pub time: i32,
+}
+
+unit_system!(
+    Quantity,
+    Dimension,
+    [
+        def Length = { length: 1 },
+        def Time = { time: 1 },
+        def Velocity = Length / Time,
+        def Acceleration = Jerk * Time,
+        def Jerk = Snap * Time,
+        def Snap = Acceleration / (Time * Time),
+        def Force = Acceleration,
+        unit (meters, "m") = Length,
+        unit (feet, "ft") = 0.3048 * feet,
+        unit (yards, "yd") = 3 * feet,
+    ]
+);
+
+fn main() {}
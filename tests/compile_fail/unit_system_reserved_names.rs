@@ -0,0 +1,23 @@
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs, adt_const_params)]
+use ::diman::dimension;
+use ::diman::unit_system;
+
+#[dimension]
+pub struct Dimension {
+    pub length: i32,
+}
+
+unit_system!(
+    Quantity,
+    Dimension,
+    [
+        def Dimensionless = {},
+        unit e = Dimensionless,
+        def Length = { length: 1 },
+        unit (meters, "m") = Length,
+        constant pi = 3.14159 * meters,
+    ]
+);
+
+fn main() {}
@@ -0,0 +1,25 @@
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs, adt_const_params)]
+use ::diman::dimension;
+use ::diman::unit_system;
+
+#[dimension]
+pub struct Dimension {
+    pub length: i32,
+    pub time: i32,
+}
+
+unit_system!(
+    Quantity,
+    Dimension,
+    [
+        def Length = { length: 1 },
+        unit meters Length,
+        def Time = { time: 1 },
+        unit (seconds, "s") = 1.0 * Time,
+        quantity Velocity = Length / Time,
+        def Acceleration = Length / Time / Time,
+    ]
+);
+
+fn main() {}
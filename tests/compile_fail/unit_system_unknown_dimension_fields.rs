@@ -0,0 +1,31 @@
+#![allow(incomplete_features)]
+#![feature(generic_const_exprs, adt_const_params)]
+use ::diman::dimension;
+use ::diman::unit_system;
+
+#[dimension]
+pub struct Dimension {
+    pub length: i32,
+    pub time: i32,
+}
+
+unit_system!(
+    Quantity,
+    Dimension,
+    [
+        def Length = { length: 1 },
+        unit (meters, "m") = Length,
+        def Time = { time: 1 },
+        unit (seconds, "s") = Time,
+        def Mass = { mass: 1 },
+        unit (grams, "g") = Mass,
+        base mass = grams,
+        def Force = { length: 1, mass: 1, time: -2 },
+        unit (dynes, "dyn") = grams * meters / seconds^2,
+        def Charge = { charge: 1 },
+        unit (statcoulombs, "statC") = Charge,
+        def Velocity = { lenght: 1, time: -1 },
+    ]
+);
+
+fn main() {}
@@ -0,0 +1,97 @@
+macro_rules! gen_tests_for_complex {
+    ($float_name: ident, $mod_name: ident, $complex_name: ty) => {
+        mod $mod_name {
+            use diman::si::$float_name::{Dimensionless, Inductance, Resistance, Voltage};
+            use diman::si::$float_name::{Frequency, Length, Power};
+            use diman::si::$mod_name::Current as ComplexCurrent;
+            use diman::si::$mod_name::Dimensionless as ComplexDimensionless;
+            use diman::si::$mod_name::Length as ComplexLength;
+            use diman::si::$mod_name::Resistance as ComplexResistance;
+            use diman::si::$mod_name::Voltage as ComplexVoltage;
+            use $complex_name as Complex;
+
+            fn assert_is_close(x: $float_name, y: $float_name) {
+                assert!((x - y).abs() < 1e-5, "{} {}", x, y);
+            }
+
+            #[test]
+            fn constructors_and_accessors() {
+                let impedance = ComplexResistance::ohms(3.0, 4.0);
+                assert_eq!(impedance.re(), Resistance::ohms(3.0));
+                assert_eq!(impedance.im(), Resistance::ohms(4.0));
+                assert_eq!(impedance.norm(), Resistance::ohms(5.0));
+                assert_eq!(impedance.abs(), Resistance::ohms(5.0));
+                assert_is_close(impedance.arg().value(), (4.0 as $float_name).atan2(3.0));
+                assert_eq!(impedance.conj(), ComplexResistance::ohms(3.0, -4.0));
+                assert_eq!(
+                    ComplexResistance::kiloohms(1.0, 2.0),
+                    ComplexResistance::ohms(1000.0, 2000.0)
+                );
+                assert_eq!(
+                    ComplexResistance::new(Resistance::ohms(3.0), Resistance::ohms(4.0)),
+                    impedance
+                );
+                assert_is_close(impedance.in_kiloohms().im, 0.004);
+            }
+
+            #[test]
+            fn impedance_of_rl_circuit() {
+                // Z = R + i ω L
+                let resistance = Resistance::ohms(3.0);
+                let inductance = Inductance::henries(2.0);
+                let angular_frequency = Frequency::hertz(2.0);
+                let reactance: Resistance = angular_frequency * inductance;
+                let impedance = ComplexResistance::new(resistance, reactance);
+                let voltage = ComplexVoltage::volts(10.0, 0.0);
+                let current: ComplexCurrent = voltage / impedance;
+                let back: ComplexVoltage = current * impedance;
+                assert_is_close(back.re().value_unchecked(), 10.0);
+                assert_is_close(back.im().value_unchecked(), 0.0);
+                let apparent_power: Power = voltage.norm() * current.norm();
+                assert_is_close(apparent_power.value_unchecked(), 20.0);
+            }
+
+            #[test]
+            fn polar() {
+                let voltage = ComplexVoltage::from_polar(
+                    Voltage::volts(2.0),
+                    Dimensionless::dimensionless(std::f64::consts::FRAC_PI_2 as $float_name),
+                );
+                assert_is_close(voltage.re().value_unchecked(), 0.0);
+                assert_is_close(voltage.im().value_unchecked(), 2.0);
+            }
+
+            #[test]
+            fn arithmetic_with_storage_and_float_quantities() {
+                let x = ComplexLength::meters(1.0, 2.0);
+                assert_eq!(x + x, ComplexLength::meters(2.0, 4.0));
+                assert_eq!(x * Complex::new(0.0, 1.0), ComplexLength::meters(-2.0, 1.0));
+                assert_eq!(x * 2.0, ComplexLength::meters(2.0, 4.0));
+                let scaled = x / Length::meters(2.0);
+                assert_eq!(scaled.value(), Complex::new(0.5, 1.0));
+                assert_eq!(-x, ComplexLength::meters(-1.0, -2.0));
+            }
+
+            #[test]
+            fn exp_and_ln_of_dimensionless() {
+                let phase =
+                    ComplexDimensionless::dimensionless(0.0, std::f64::consts::PI as $float_name);
+                let rotated = phase.exp();
+                assert_is_close(rotated.re().value(), -1.0);
+                assert_is_close(rotated.im().value(), 0.0);
+                let x = ComplexDimensionless::dimensionless(1.0, 1.0);
+                assert_is_close(x.exp().ln().re().value(), 1.0);
+                assert_is_close(x.exp().ln().im().value(), 1.0);
+            }
+
+            #[test]
+            fn debug() {
+                assert_eq!(format!("{:?}", ComplexResistance::ohms(3.0, 4.0)), "3+4i Ω");
+            }
+        }
+    };
+}
+
+gen_tests_for_complex!(f32, complex32, num_complex::Complex32);
+
+gen_tests_for_complex!(f64, complex64, num_complex::Complex64);
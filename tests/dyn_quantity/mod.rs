@@ -0,0 +1,60 @@
+macro_rules! gen_tests_for_float {
+    ($float_name: ident, $assert_is_close: path) => {
+        mod $float_name {
+            use crate::example_system::$float_name::Length;
+            use crate::example_system::$float_name::Time;
+            use crate::example_system::$float_name::Velocity;
+            use crate::example_system::DimensionMismatch;
+            use crate::example_system::DynQuantity;
+            use $assert_is_close as assert_is_close;
+
+            #[test]
+            fn roundtrip() {
+                let x: DynQuantity<_> = Length::meters(5.0).into();
+                let y: Length = x.try_into().unwrap();
+                assert_is_close(y, Length::meters(5.0));
+            }
+
+            #[test]
+            fn try_from_mismatch() {
+                let x: DynQuantity<_> = Length::meters(5.0).into();
+                let y: Result<Time, _> = x.try_into();
+                assert_eq!(
+                    y.unwrap_err(),
+                    DimensionMismatch {
+                        expected: DynQuantity::from(Time::seconds(1.0)).dimension().clone(),
+                        found: DynQuantity::from(Length::meters(1.0)).dimension().clone(),
+                    }
+                );
+            }
+
+            #[test]
+            fn try_add() {
+                let x: DynQuantity<_> = Length::meters(5.0).into();
+                let y: DynQuantity<_> = Length::kilometers(1.0).into();
+                let z: Length = x.try_add(y).unwrap().try_into().unwrap();
+                assert_is_close(z, Length::meters(1005.0));
+                let x: DynQuantity<_> = Length::meters(5.0).into();
+                let y: DynQuantity<_> = Time::seconds(1.0).into();
+                assert!(x.clone().try_add(y.clone()).is_err());
+                assert!(x.try_sub(y).is_err());
+            }
+
+            #[test]
+            fn mul_div() {
+                let x: DynQuantity<_> = Length::meters(6.0).into();
+                let y: DynQuantity<_> = Time::seconds(2.0).into();
+                let v: Velocity = (x.clone() / y.clone()).try_into().unwrap();
+                assert_is_close(v, Velocity::meters_per_second(3.0));
+                let z: Result<Velocity, _> = (x * y).try_into();
+                assert!(z.is_err());
+            }
+        }
+    };
+}
+
+#[cfg(feature = "f32")]
+gen_tests_for_float!(f32, crate::utils::assert_is_close_f32);
+
+#[cfg(feature = "f64")]
+gen_tests_for_float!(f64, crate::utils::assert_is_close_f64);
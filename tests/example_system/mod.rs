@@ -7,6 +7,7 @@ pub struct Dimension {
     pub time: i32,
     pub mass: i32,
     pub temperature: i32,
+    pub information: i32,
 }
 
 unit_system!(
@@ -18,8 +19,12 @@ unit_system!(
         def Length = { length: 1 },
         unit (meters, "m") = Length,
         unit (kilometers, "km") = 1000.0 * meters,
+        unit (millimeters, "mm") = 1e-3 * meters,
+        unit (micrometers, "µm") = 1e-6 * meters,
         def Time = { time: 1 },
         unit (seconds, "s") = 1.0 * Time,
+        unit (milliseconds, "ms") = 1e-3 * seconds,
+        unit (hours, "h") = 3600 * seconds,
         def Velocity = Length / Time,
         unit (meters_per_second, "m/s") = meters / seconds,
         def Energy = Mass * Velocity * Velocity,
@@ -27,12 +32,31 @@ unit_system!(
         def Mass = { mass: 1 },
         unit (kilograms, "kg") = Mass,
         unit (grams, "g") = 1e-3 * kilograms,
+        def Temperature = { temperature: 1 },
+        unit (kelvin, "K") = Temperature,
+        unit (celsius, "°C") = kelvin + 273.15,
+        unit (fahrenheit, "°F") = (5.0 / 9.0) * kelvin + 459.67,
+        def Information = { information: 1 },
+        unit (bytes, "B", [kilo, mega, binary_prefixes]) = Information,
         def Area = Length * Length,
         def Volume = Length * Length * Length,
+        def Acceleration = Length / Time^2,
+        unit meters_per_second_squared = meters / seconds^2,
+        unit (liters, "L", [milli, metric_prefixes]) = 1e-3 * Volume,
         def Force = Energy / Length,
         unit (newtons, "N") = joules / meters,
+        unit kilowatt_hours: Energy = 3.6e6 * joules,
+        def Power = Energy / Time,
+        unit (watts, "W", [milli]) = joules / seconds,
+        unit (dbm, "dBm") = 10 * log10(milliwatts),
+        unit (bel_watts, "BW", [deci]) = log10(watts),
+        unit (nepers_watt, "NpW") = 0.5 * ln(watts),
+        def Irradiance = Power / Area,
+        unit watts_per_square_meter = watts / (meters * meters),
+        unit (apparent_magnitudes, "mag") = -2.5 * log10(2.518021002e-8 * watts_per_square_meter),
         constant SOLAR_MASS = 1.988477e30 * kilograms,
         constant SOLAR_MASS_GRAMS = 1.988477e33 * grams,
         constant SOLAR_MASS_AWKWARD = 1.988477e30 * kilograms / (seconds / seconds),
+        constant SOLAR_MASS_DECLARED: Mass = 1.988477e30 * kilograms,
     ]
 );
@@ -1,15 +1,19 @@
 macro_rules! gen_tests_for_float {
     ($float_name: ident, $mod_name: ident, $assert_is_close: path, $assert_is_close_float: path) => {
         mod $mod_name {
+            use crate::example_system::$float_name::Acceleration;
             use crate::example_system::$float_name::Dimensionless;
             use crate::example_system::$float_name::Energy;
             use crate::example_system::$float_name::Force;
+            use crate::example_system::$float_name::Information;
             use crate::example_system::$float_name::Length;
             use crate::example_system::$float_name::Mass;
             use crate::example_system::$float_name::Time;
             use crate::example_system::$float_name::Velocity;
+            use crate::example_system::$float_name::Volume;
             use crate::example_system::$float_name::SOLAR_MASS;
             use crate::example_system::$float_name::SOLAR_MASS_AWKWARD;
+            use crate::example_system::$float_name::SOLAR_MASS_DECLARED;
             use crate::example_system::$float_name::SOLAR_MASS_GRAMS;
             use $assert_is_close as assert_is_close;
             use $assert_is_close_float as assert_is_close_float;
@@ -77,6 +81,59 @@ macro_rules! gen_tests_for_float {
                 assert_is_close(items.into_iter().sum(), Length::meters(4012.0));
             }
 
+            #[test]
+            fn prefixed_units() {
+                assert_is_close(Information::kilobytes(1.0), Information::bytes(1000.0));
+                assert_is_close(Information::megabytes(1.0), Information::kilobytes(1000.0));
+                assert_is_close(Volume::milliliters(1.0), Volume::liters(0.001));
+            }
+
+            #[test]
+            fn prefix_groups() {
+                assert_is_close(Volume::microliters(1000.0), Volume::milliliters(1.0));
+                assert_is_close(Volume::deciliters(10.0), Volume::liters(1.0));
+                assert_is_close(Information::kibibytes(1.0), Information::bytes(1024.0));
+                assert_is_close(Information::mebibytes(1.0), Information::kibibytes(1024.0));
+                assert_is_close(Information::kilobytes(1.0), Information::bytes(1000.0));
+            }
+
+            #[test]
+            fn overlapping_prefix_groups() {
+                assert_is_close(Volume::milliliters(1000.0), Volume::liters(1.0));
+                assert_is_close(Volume::kiloliters(1.0), Volume::liters(1000.0));
+            }
+
+            #[test]
+            fn debug_prefixed_units() {
+                assert_eq!(format!("{:?}", Information::bytes(2048.0)), "2 KiB");
+                assert_eq!(format!("{:?}", Volume::liters(2000.0)), "2 kL");
+            }
+
+            #[test]
+            fn display() {
+                assert_eq!(format!("{}", Length::meters(2000.0)), "2 km");
+                assert_eq!(format!("{:.2}", Length::meters(1500.0)), "1.50 km");
+                assert_eq!(format!("{:>8.1}", Length::meters(1500.0)), "  1.5 km");
+                assert_eq!(format!("{:<8}", Length::meters(1.0)), "1 m     ");
+            }
+
+            #[test]
+            fn display_in() {
+                let x = Length::meters(1500.0);
+                assert_eq!(format!("{}", x.display_in_meters()), "1500 m");
+                assert_eq!(format!("{:.1}", x.display_in_kilometers()), "1.5 km");
+                let v = Velocity::meters_per_second(2.0);
+                assert_eq!(format!("{}", v.display_in_meters_per_second()), "2 m/s");
+            }
+
+            #[test]
+            fn display_as() {
+                let x = Length::meters(1500.0);
+                assert_eq!(format!("{:.3}", x.display_as("km").unwrap()), "1.500 km");
+                assert!(x.display_as("s").is_none());
+                assert!(x.display_as("unknown").is_none());
+            }
+
             #[test]
             fn sub_different_units() {
                 let x = Length::meters(1.0);
@@ -239,6 +296,16 @@ macro_rules! gen_tests_for_float {
                 assert_is_close(SOLAR_MASS, Mass::kilograms(1.988477e30));
                 assert_is_close(SOLAR_MASS_GRAMS, Mass::kilograms(1.988477e30));
                 assert_is_close(SOLAR_MASS_AWKWARD, Mass::kilograms(1.988477e30));
+                assert_is_close(SOLAR_MASS_DECLARED, Mass::kilograms(1.988477e30));
+            }
+
+            #[test]
+            fn declared_quantities_and_powers() {
+                assert_is_close(Energy::kilowatt_hours(1.0), Energy::joules(3.6e6));
+                assert_is_close(
+                    Acceleration::meters_per_second_squared(2.0),
+                    Length::meters(2.0) / (Time::seconds(1.0) * Time::seconds(1.0)),
+                );
             }
 
             #[test]
@@ -130,6 +130,104 @@ macro_rules! gen_tests_for_vector_3 {
                 assert_is_close(divided.y(), 10.0 * base);
                 assert_is_close(divided.z(), 15.0 * base);
             }
+
+            #[test]
+            fn torque_is_cross_product() {
+                use crate::example_system::$mod_name::Energy as VecEnergy;
+                use crate::example_system::$mod_name::Force as VecForce;
+                let r = VecLength::meters(2.0, 0.0, 0.0);
+                let force = VecForce::newtons(0.0, 3.0, 0.0);
+                let torque: VecEnergy = r.cross(force);
+                assert_eq!(torque, VecEnergy::joules(0.0, 0.0, 6.0));
+            }
+
+            #[test]
+            fn lerp_project_and_reject() {
+                let x = VecLength::meters(1.0, 2.0, 0.0);
+                let y = VecLength::meters(3.0, 2.0, 4.0);
+                assert_eq!(x.lerp(y, 0.5), VecLength::meters(2.0, 2.0, 2.0));
+                let direction = VecVelocity::meters_per_second(0.0, 0.0, 5.0);
+                assert_eq!(y.project_onto(direction), VecLength::meters(0.0, 0.0, 4.0));
+                assert_eq!(y.reject_from(direction), VecLength::meters(3.0, 2.0, 0.0));
+            }
+
+            #[test]
+            fn angle_between() {
+                let x = VecLength::meters(1.0, 0.0, 0.0);
+                let v = VecVelocity::meters_per_second(0.0, 2.0, 0.0);
+                let angle = x.angle_between(v);
+                assert!((angle.value() - std::f64::consts::FRAC_PI_2 as $float_name).abs() < 1e-3);
+            }
+
+            #[test]
+            fn clamp_length_and_abs_diff_eq() {
+                let x = VecLength::meters(3.0, 0.0, 4.0);
+                let clamped = x.clamp_length(Length::meters(1.0), Length::meters(2.5));
+                assert_is_close(clamped.length(), Length::meters(2.5));
+                let unchanged = x.clamp_length(Length::meters(1.0), Length::meters(10.0));
+                assert_eq!(unchanged, x);
+                let y = VecLength::meters(3.0, 0.0, 4.001);
+                assert!(x.abs_diff_eq(y, Length::millimeters(2.0)));
+                assert!(!x.abs_diff_eq(y, Length::micrometers(500.0)));
+            }
+        }
+    };
+}
+
+#[cfg(any(feature = "glam-vec4", feature = "glam-dvec4"))]
+macro_rules! gen_tests_for_vector_4 {
+    ($float_name: ident, $mod_name: ident, $vec_name: ty, $assert_is_close: path) => {
+        mod $mod_name {
+            use crate::example_system::$float_name::Length;
+            use crate::example_system::$float_name::Time;
+            use crate::example_system::$mod_name::Length as VecLength;
+            use crate::example_system::$mod_name::Velocity as VecVelocity;
+            use $assert_is_close as assert_is_close;
+            use $vec_name as Vec;
+
+            #[test]
+            fn debug_vector_4() {
+                assert_eq!(
+                    format!("{:?}", VecLength::meters(1.0, 5.0, 6.0, 7.0)),
+                    "[1, 5, 6, 7] m"
+                );
+            }
+
+            #[test]
+            fn components() {
+                let mut x = VecLength::new(
+                    Length::meters(1.0),
+                    Length::meters(2.0),
+                    Length::meters(3.0),
+                    Length::meters(4.0),
+                );
+                assert_is_close(x.w(), Length::meters(4.0));
+                x.set_w(Length::kilometers(1.0));
+                assert_eq!(x, VecLength::meters(1.0, 2.0, 3.0, 1000.0));
+                assert_eq!(
+                    VecLength::new_w(Length::meters(2.0)),
+                    VecLength::meters(0.0, 0.0, 0.0, 2.0)
+                );
+            }
+
+            #[test]
+            fn mul_quantity_vec4() {
+                let multiplied =
+                    VecVelocity::meters_per_second(1.0, 2.0, 3.0, 4.0) * Time::seconds(5.0);
+                assert_eq!(multiplied, VecLength::meters(5.0, 10.0, 15.0, 20.0));
+                let multiplied = Vec::new(1.0, 2.0, 3.0, 4.0) * Length::meters(5.0);
+                assert_eq!(multiplied, VecLength::meters(5.0, 10.0, 15.0, 20.0));
+            }
+
+            #[test]
+            fn length_and_angle_between() {
+                let x = VecLength::meters(1.0, 1.0, 1.0, 1.0);
+                assert_is_close(x.length(), Length::meters(2.0));
+                let y = VecLength::meters(0.0, 0.0, 0.0, 3.0);
+                let angle = x.angle_between(y);
+                assert!((angle.value() - std::f64::consts::FRAC_PI_3 as $float_name).abs() < 1e-5);
+                assert!((x.angle_between(x).value()).abs() < 1e-3);
+            }
         }
     };
 }
@@ -145,3 +243,65 @@ gen_tests_for_vector_3!(f32, vec3, glam::Vec3, crate::utils::assert_is_close_f32
 
 #[cfg(all(feature = "f64", feature = "glam-dvec3"))]
 gen_tests_for_vector_3!(f64, dvec3, glam::DVec3, crate::utils::assert_is_close_f64);
+
+#[cfg(all(feature = "f32", feature = "glam-vec4"))]
+gen_tests_for_vector_4!(f32, vec4, glam::Vec4, crate::utils::assert_is_close_f32);
+
+#[cfg(all(feature = "f64", feature = "glam-dvec4"))]
+gen_tests_for_vector_4!(f64, dvec4, glam::DVec4, crate::utils::assert_is_close_f64);
+
+#[cfg(feature = "glam-ivec2")]
+mod ivec2 {
+    use crate::example_system::i32::Area;
+    use crate::example_system::ivec2::Length as VecLength;
+    use glam::IVec2;
+
+    #[test]
+    fn constructors_and_conversions() {
+        let x = VecLength::kilometers(1, 2);
+        assert_eq!(x, VecLength::meters(1000, 2000));
+        assert_eq!(x.in_meters(), IVec2::new(1000, 2000));
+        assert_eq!(format!("{:?}", VecLength::meters(1000, 2000)), "[1, 2] km");
+        assert_eq!(
+            format!("{:?}", VecLength::meters(1500, 2000)),
+            "[1500, 2000] m"
+        );
+    }
+
+    #[test]
+    fn methods() {
+        let x = VecLength::meters(3, 4);
+        let y = VecLength::meters(1, 0);
+        assert_eq!(x.length_squared(), Area::new_unchecked(25));
+        assert_eq!(x.distance_squared(&y), Area::new_unchecked(20));
+        assert_eq!(x.dot(y), Area::new_unchecked(3));
+        assert_eq!(x.min(y), VecLength::meters(1, 0));
+        assert_eq!(x + y, VecLength::meters(4, 4));
+        assert_eq!(x * 2, VecLength::meters(6, 8));
+    }
+
+    #[test]
+    #[should_panic]
+    fn constructor_panics_on_overflow() {
+        let _ = VecLength::kilometers(i32::MAX, 0);
+    }
+}
+
+#[cfg(feature = "glam-ivec3")]
+mod ivec3 {
+    use crate::example_system::i32::Length;
+    use crate::example_system::ivec3::{Area as VecArea, Length as VecLength};
+
+    #[test]
+    fn cross() {
+        let x = VecLength::meters(1, 0, 0);
+        let y = VecLength::meters(0, 2, 0);
+        let area: VecArea = x.cross(y);
+        assert_eq!(area.z().value_unchecked(), 2);
+        assert_eq!(x.z(), Length::meters(0));
+        assert_eq!(
+            VecLength::new_z(Length::meters(3)),
+            VecLength::meters(0, 0, 3)
+        );
+    }
+}
@@ -0,0 +1,210 @@
+use ::diman::dimension;
+use ::diman::unit_system;
+
+#[dimension]
+pub struct Dimension {
+    pub length: i32,
+    pub time: i32,
+}
+
+// Lengths are stored in millimeters and times in milliseconds,
+// so that they can be represented by integers.
+unit_system!(
+    Quantity,
+    Dimension,
+    [
+        def Dimensionless = {},
+        unit dimensionless = Dimensionless,
+        def Length = { length: 1 },
+        unit (meters, "m", [kilo, milli, micro]) = Length,
+        unit (inches, "in") = 0.0254 * meters,
+        def Time = { time: 1 },
+        unit (seconds, "s", [milli]) = Time,
+        def Velocity = Length / Time,
+        unit (meters_per_second, "m/s") = meters / seconds,
+        def Area = Length * Length,
+        def Temperature = Dimensionless,
+        unit (celsius, "°C") = dimensionless + 273,
+        base length = millimeters,
+        base time = milliseconds,
+    ]
+);
+
+macro_rules! gen_tests_for_int {
+    ($int_name: ident) => {
+        mod $int_name {
+            use super::super::$int_name::Area;
+            use super::super::$int_name::Length;
+            use super::super::$int_name::Temperature;
+            use super::super::$int_name::Time;
+            use super::super::$int_name::Velocity;
+
+            #[test]
+            fn constructors() {
+                assert_eq!(Length::millimeters(3).value_unchecked(), 3);
+                assert_eq!(Length::meters(3).value_unchecked(), 3000);
+                assert_eq!(Length::kilometers(3).value_unchecked(), 3000000);
+                assert_eq!(Time::seconds(2), Time::milliseconds(2000));
+                assert_eq!(Temperature::celsius(1).absolute().value_unchecked(), 274);
+                assert_eq!(Temperature::celsius_difference(1).value_unchecked(), 1);
+            }
+
+            #[test]
+            fn conversions() {
+                assert_eq!(Length::meters(3).in_millimeters(), 3000);
+                assert_eq!(Length::millimeters(1500).in_micrometers(), 1500000);
+                assert_eq!(Time::seconds(2).in_milliseconds(), 2000);
+                assert_eq!(Temperature::celsius(1).in_celsius(), 1);
+                assert_eq!(Temperature::celsius_difference(1).in_celsius_difference(), 1);
+            }
+
+            #[test]
+            fn arithmetic() {
+                let length = Length::meters(3) + Length::millimeters(5);
+                assert_eq!(length, Length::millimeters(3005));
+                let area: Area = length * Length::millimeters(2);
+                assert_eq!(area.value_unchecked(), 6010);
+                let velocity: Velocity = Length::meters(10) / Time::seconds(2);
+                assert_eq!(velocity.value_unchecked(), 5);
+                assert_eq!(-Length::meters(1), Length::millimeters(-1000));
+                assert_eq!(Length::meters(1) * 3, Length::meters(3));
+                assert_eq!(Length::meters(2).abs(), Length::meters(-2).abs());
+                assert!(Length::meters(1) < Length::meters(2));
+                assert_eq!(
+                    Length::meters(1).max(Length::millimeters(1)),
+                    Length::meters(1)
+                );
+            }
+
+            #[test]
+            fn checked_arithmetic() {
+                let max = Length::millimeters($int_name::MAX);
+                assert_eq!(max.checked_add(Length::millimeters(1)), None);
+                assert_eq!(
+                    Length::meters(1).checked_sub(Length::millimeters(1)),
+                    Some(Length::millimeters(999))
+                );
+                assert_eq!(max.checked_mul(Length::millimeters(2)), None);
+                let area: Option<Area> = Length::millimeters(2).checked_mul(Length::millimeters(3));
+                assert_eq!(area.map(|area| area.value_unchecked()), Some(6));
+                assert_eq!(Length::meters(1).checked_div(Time::milliseconds(0)), None);
+                assert_eq!(Length::millimeters($int_name::MIN).checked_neg(), None);
+            }
+
+            #[test]
+            fn wrapping_arithmetic() {
+                let max = Length::millimeters($int_name::MAX);
+                assert_eq!(
+                    max.wrapping_add(Length::millimeters(1)),
+                    Length::millimeters($int_name::MIN)
+                );
+                assert_eq!(
+                    Length::millimeters($int_name::MIN).wrapping_sub(Length::millimeters(1)),
+                    max
+                );
+                assert_eq!(
+                    Length::millimeters($int_name::MIN).wrapping_neg(),
+                    Length::millimeters($int_name::MIN)
+                );
+            }
+
+            #[test]
+            fn saturating_arithmetic() {
+                let max = Length::millimeters($int_name::MAX);
+                assert_eq!(max.saturating_add(Length::meters(1)), max);
+                assert_eq!(
+                    Length::millimeters($int_name::MIN).saturating_sub(Length::meters(1)),
+                    Length::millimeters($int_name::MIN)
+                );
+                let area: Area = max.saturating_mul(Length::millimeters(-2));
+                assert_eq!(area.value_unchecked(), $int_name::MIN);
+                let velocity: Velocity = Length::meters(6).saturating_div(Time::seconds(2));
+                assert_eq!(velocity.value_unchecked(), 3);
+            }
+
+            #[test]
+            fn debug_prints_in_exact_units() {
+                assert_eq!(format!("{:?}", Length::meters(2)), "2 m");
+                assert_eq!(format!("{:?}", Length::millimeters(1500)), "1500 mm");
+                assert_eq!(format!("{:?}", Length::kilometers(3)), "3 km");
+                assert_eq!(format!("{}", Time::milliseconds(2500)), "2500 ms");
+                assert_eq!(format!("{}", Time::seconds(2)), "2 s");
+            }
+
+            #[test]
+            fn from_str() {
+                assert_eq!("2 m".parse::<Length>().unwrap(), Length::meters(2));
+                assert_eq!(
+                    "1.5 m".parse::<Length>().unwrap(),
+                    Length::millimeters(1500)
+                );
+                assert_eq!("5 in".parse::<Length>().unwrap(), Length::millimeters(127));
+                assert_eq!("3 m/s".parse::<Velocity>().unwrap().value_unchecked(), 3);
+            }
+
+            #[test]
+            fn from_str_is_exact_for_integers() {
+                let max = format!("{} mm", $int_name::MAX);
+                assert_eq!(
+                    max.parse::<Length>().unwrap(),
+                    Length::millimeters($int_name::MAX)
+                );
+                let max_in_meters = format!("{} m", $int_name::MAX / 1000);
+                assert_eq!(
+                    max_in_meters.parse::<Length>().unwrap(),
+                    Length::millimeters($int_name::MAX / 1000 * 1000)
+                );
+                assert_eq!("3000 µm".parse::<Length>().unwrap(), Length::millimeters(3));
+            }
+
+            #[test]
+            fn from_str_rejects_non_integer_values() {
+                use super::super::UnitParseErrorKind;
+                let err = "1 in".parse::<Length>().unwrap_err();
+                assert_eq!(err.kind, UnitParseErrorKind::NonIntegerValue);
+                let err = "1 µm".parse::<Length>().unwrap_err();
+                assert_eq!(err.kind, UnitParseErrorKind::NonIntegerValue);
+                let err = "1e30 km".parse::<Length>().unwrap_err();
+                assert_eq!(err.kind, UnitParseErrorKind::NonIntegerValue);
+                let err = format!("{} m", $int_name::MAX)
+                    .parse::<Length>()
+                    .unwrap_err();
+                assert_eq!(err.kind, UnitParseErrorKind::NonIntegerValue);
+            }
+
+            #[cfg(feature = "serde")]
+            #[test]
+            fn serde() {
+                let length: Length = serde_yaml::from_str("3 m").unwrap();
+                assert_eq!(length, Length::meters(3));
+                assert_eq!(serde_yaml::to_string(&length).unwrap().trim(), "3000 mm");
+                assert!(serde_yaml::from_str::<Length>("0.5 mm").is_err());
+            }
+        }
+    };
+}
+
+mod tests {
+    #[cfg(feature = "i32")]
+    gen_tests_for_int!(i32);
+
+    #[cfg(feature = "i64")]
+    gen_tests_for_int!(i64);
+
+    #[cfg(feature = "i64")]
+    #[test]
+    fn conversions_are_exact_beyond_f64_precision() {
+        use super::i64::Length;
+        let value = (1 << 53) + 1;
+        assert_eq!(Length::millimeters(value).in_millimeters(), value);
+        assert_eq!(Length::millimeters(value).in_micrometers(), value * 1000);
+    }
+
+    #[cfg(feature = "i32")]
+    #[test]
+    #[should_panic]
+    fn constructor_panics_on_overflow() {
+        use super::i32::Length;
+        let _ = Length::kilometers(i32::MAX);
+    }
+}
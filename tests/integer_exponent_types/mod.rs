@@ -0,0 +1,127 @@
+use ::diman::dimension;
+use ::diman::unit_system;
+
+#[dimension]
+pub struct Dimension {
+    pub length: i8,
+    pub time: i64,
+}
+
+unit_system!(
+    Quantity,
+    Dimension: i8,
+    [
+        def Dimensionless = {},
+        unit dimensionless = Dimensionless,
+        def Length = { length: 1 },
+        unit (meters, "m", [kilo]) = Length,
+        def Time = { time: 1 },
+        unit (seconds, "s") = Time,
+        def Area = Length * Length,
+        unit (square_meters, "m^2") = meters * meters,
+        def Velocity = Length / Time,
+        unit (meters_per_second, "m/s") = meters / seconds,
+        def LargeVolume = { length: 127 },
+        unit (large_cubic_meters, "m^127") = LargeVolume,
+    ]
+);
+
+macro_rules! gen_tests_for_float {
+    ($float_name: ident) => {
+        mod $float_name {
+            use super::super::$float_name::Area;
+            use super::super::$float_name::LargeVolume;
+            use super::super::$float_name::Length;
+            use super::super::$float_name::Time;
+            use super::super::$float_name::Velocity;
+            use super::super::Dimension;
+            use super::super::DynQuantity;
+
+            fn dimension<S>(quantity: impl Into<DynQuantity<S>>) -> Dimension {
+                quantity.into().dimension().clone()
+            }
+
+            #[test]
+            fn arithmetic() {
+                let x = Length::meters(2.0) / Time::seconds(4.0);
+                assert_eq!(x, Velocity::meters_per_second(0.5));
+                assert_eq!(
+                    dimension(x / Time::seconds(1.0)),
+                    Dimension {
+                        length: 1,
+                        time: -2,
+                    }
+                );
+            }
+
+            #[test]
+            fn powers_and_roots() {
+                let x = Length::meters(2.0);
+                assert_eq!(x.powi::<2>(), Area::square_meters(4.0));
+                assert_eq!(x.powi::<2>().sqrt(), x);
+                assert_eq!(
+                    dimension(Time::seconds(2.0).powi::<-3>()),
+                    Dimension {
+                        length: 0,
+                        time: -3,
+                    }
+                );
+            }
+
+            #[test]
+            fn extreme_exponents() {
+                let x = LargeVolume::large_cubic_meters(1.0);
+                assert_eq!(
+                    dimension(x / x / x),
+                    Dimension {
+                        length: -127,
+                        time: 0,
+                    }
+                );
+            }
+
+            #[test]
+            fn from_str() {
+                let x: DynQuantity<$float_name> = "3 m^2 s^-1".parse().unwrap();
+                assert_eq!(x, (Area::square_meters(3.0) / Time::seconds(1.0)).into());
+                assert_eq!(
+                    format!("{}", Time::seconds(2.0).powi::<-3>()),
+                    "0.125 s^-3"
+                );
+            }
+
+            #[test]
+            fn from_str_exponent_overflow() {
+                use super::super::UnitParseErrorKind;
+                let err = "1 m^200".parse::<DynQuantity<$float_name>>().unwrap_err();
+                assert_eq!(err.kind, UnitParseErrorKind::InvalidExponent);
+                assert_eq!(err.token, "m");
+                let err = "1 m^100 m^100"
+                    .parse::<DynQuantity<$float_name>>()
+                    .unwrap_err();
+                assert_eq!(err.kind, UnitParseErrorKind::InvalidExponent);
+                assert_eq!(err.column, 9);
+            }
+        }
+    };
+}
+
+mod tests {
+    #[cfg(feature = "f32")]
+    gen_tests_for_float!(f32);
+
+    #[cfg(feature = "f64")]
+    gen_tests_for_float!(f64);
+
+    #[test]
+    fn dimension_checked_root() {
+        use super::Dimension;
+        let dimension = Dimension { length: 2, time: 4 };
+        assert_eq!(
+            dimension.clone().dimension_checked_root(2),
+            Some(Dimension { length: 1, time: 2 })
+        );
+        assert_eq!(dimension.clone().dimension_checked_root(3), None);
+        assert_eq!(dimension.dimension_checked_root(0), None);
+    }
+}
@@ -0,0 +1,78 @@
+macro_rules! gen_tests_for_float {
+    ($float_name: ident, $assert_is_close_float: path) => {
+        mod $float_name {
+            use crate::example_system::$float_name::Irradiance;
+            use crate::example_system::$float_name::Power;
+            use crate::example_system::DynQuantity;
+            use crate::example_system::UnitParseErrorKind;
+            use $assert_is_close_float as assert_is_close_float;
+
+            fn assert_is_close_relative(x: $float_name, y: $float_name) {
+                assert!(((x - y) / y).abs() < 1e-5, "{} {}", x, y);
+            }
+
+            fn assert_is_close_absolute(x: $float_name, y: $float_name) {
+                assert!((x - y).abs() < 1e-4, "{} {}", x, y);
+            }
+
+            #[test]
+            fn decibel_milliwatts() {
+                assert_is_close_relative(Power::from_dbm(0.0).value_unchecked(), 1e-3);
+                assert_is_close_relative(Power::from_dbm(30.0).value_unchecked(), 1.0);
+                assert_is_close_relative(Power::from_dbm(-10.0).value_unchecked(), 1e-4);
+                assert_is_close_absolute(Power::watts(1.0).in_dbm(), 30.0);
+                assert_is_close_absolute(Power::milliwatts(100.0).in_dbm(), 20.0);
+            }
+
+            #[test]
+            fn prefixed_logarithmic_units() {
+                assert_is_close_relative(Power::from_bel_watts(1.0).value_unchecked(), 10.0);
+                assert_is_close_relative(Power::from_decibel_watts(10.0).value_unchecked(), 10.0);
+                assert_is_close_absolute(Power::watts(100.0).in_decibel_watts(), 20.0);
+                assert_is_close_absolute(Power::watts(100.0).in_bel_watts(), 2.0);
+            }
+
+            #[test]
+            fn natural_logarithm() {
+                assert_is_close_relative(
+                    Power::from_nepers_watt(0.5).value_unchecked(),
+                    std::f64::consts::E as $float_name,
+                );
+                assert_is_close_float(Power::watts(1.0).in_nepers_watt(), 0.0);
+            }
+
+            #[test]
+            fn negative_scale() {
+                let zero_point = Irradiance::from_apparent_magnitudes(0.0);
+                assert_is_close_relative(zero_point.value_unchecked(), 2.518021e-8);
+                let fainter = Irradiance::from_apparent_magnitudes(5.0);
+                assert_is_close_relative((fainter / zero_point).value_unchecked(), 0.01);
+                assert_is_close_absolute(fainter.in_apparent_magnitudes(), 5.0);
+            }
+
+            #[test]
+            fn from_str() {
+                let p: Power = "30 dBm".parse().unwrap();
+                assert_is_close_relative(p.value_unchecked(), 1.0);
+                let p: Power = "20 dBW".parse().unwrap();
+                assert_is_close_relative(p.value_unchecked(), 100.0);
+                let i: Irradiance = " 5 mag".parse().unwrap();
+                assert_is_close_relative(i.value_unchecked(), 2.518021e-10);
+            }
+
+            #[test]
+            fn from_str_logarithmic_unit_in_expression() {
+                let err = "3 dBm/s".parse::<DynQuantity<$float_name>>().unwrap_err();
+                assert_eq!(err.kind, UnitParseErrorKind::NonLinearUnit);
+                assert_eq!(err.token, "dBm");
+                assert_eq!(err.column, 3);
+            }
+        }
+    };
+}
+
+#[cfg(feature = "f32")]
+gen_tests_for_float!(f32, crate::utils::assert_is_close_float_f32);
+
+#[cfg(feature = "f64")]
+gen_tests_for_float!(f64, crate::utils::assert_is_close_float_f64);
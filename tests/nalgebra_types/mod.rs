@@ -0,0 +1,133 @@
+use diman::si::f64::{Area, Dimensionless, Energy, Length, Mass, Time};
+use diman::si::matrix3::Area as MatrixArea;
+use diman::si::unit_quaternion::Angle as Rotation;
+use diman::si::vector3::{
+    Action as VectorAction, Force as VectorForce, Frequency as VectorFrequency,
+    Length as VectorLength, Velocity as VectorVelocity,
+};
+use nalgebra::{Matrix3, Vector3};
+
+fn assert_is_close(x: Vector3<f64>, y: Vector3<f64>) {
+    assert!((x - y).norm() < 1e-10, "{} {}", x, y);
+}
+
+#[test]
+fn vector_constructors_and_accessors() {
+    let x = VectorLength::kilometers(1.0, 2.0, 3.0);
+    assert_eq!(x.x(), Length::kilometers(1.0));
+    assert_eq!(x.y(), Length::kilometers(2.0));
+    assert_eq!(x.z(), Length::kilometers(3.0));
+    assert_eq!(x.in_meters(), Vector3::new(1000.0, 2000.0, 3000.0));
+    assert_eq!(
+        VectorLength::new(
+            Length::kilometers(1.0),
+            Length::kilometers(2.0),
+            Length::kilometers(3.0)
+        ),
+        x
+    );
+    assert_eq!(
+        VectorLength::new_z(Length::meters(2.0)),
+        VectorLength::meters(0.0, 0.0, 2.0)
+    );
+    let mut y = VectorLength::zero();
+    y.set_y(Length::meters(5.0));
+    assert_eq!(y, VectorLength::meters(0.0, 5.0, 0.0));
+}
+
+#[test]
+fn vector_methods() {
+    let x = VectorLength::meters(3.0, 4.0, 0.0);
+    let y = VectorLength::meters(0.0, 0.0, 2.0);
+    assert_eq!(x.length(), Length::meters(5.0));
+    assert_eq!(x.distance(&VectorLength::zero()), Length::meters(5.0));
+    assert_eq!(
+        x.distance_squared(&VectorLength::zero()),
+        Area::square_meters(25.0)
+    );
+    assert_eq!(x.normalize().value(), Vector3::new(0.6, 0.8, 0.0));
+    let force = VectorForce::newtons(1.0, 2.0, 0.0);
+    let work: Energy = force.dot(x);
+    assert_eq!(work, Energy::joules(11.0));
+    let area = x.cross(y);
+    assert_eq!(area.value_unchecked(), Vector3::new(8.0, -6.0, 0.0));
+    assert_eq!(x.min(y), VectorLength::meters(0.0, 0.0, 0.0));
+    assert_eq!(x.max(y), VectorLength::meters(3.0, 4.0, 2.0));
+}
+
+#[test]
+fn vector_arithmetic() {
+    let x = VectorLength::meters(1.0, 2.0, 3.0);
+    let velocity: VectorVelocity = x / Time::seconds(2.0);
+    assert_eq!(velocity.in_meters_per_second(), Vector3::new(0.5, 1.0, 1.5));
+    assert_eq!(x + x, x * 2.0);
+    assert_eq!(Length::meters(2.0) * Vector3::new(1.0, 2.0, 3.0), x * 2.0);
+    assert_eq!(-x, VectorLength::meters(-1.0, -2.0, -3.0));
+}
+
+#[test]
+fn matrix_vector_product_multiplies_dimensions() {
+    // The angular momentum of a rigid body is L = I ω
+    let inertia = Mass::kilograms(2.0)
+        * MatrixArea::square_meters(Matrix3::from_diagonal(&Vector3::new(1.0, 2.0, 3.0)));
+    let angular_velocity = VectorFrequency::hertz(1.0, 1.0, 2.0);
+    let angular_momentum: VectorAction = inertia * angular_velocity;
+    assert_eq!(
+        angular_momentum.value_unchecked(),
+        Vector3::new(2.0, 4.0, 12.0)
+    );
+    let rotational_energy: Energy = angular_velocity.dot(angular_momentum) * 0.5;
+    assert_eq!(rotational_energy, Energy::joules(15.0));
+}
+
+#[test]
+fn matrix_methods() {
+    let matrix = MatrixArea::square_meters(Matrix3::new(
+        1.0, 2.0, 0.0, //
+        0.0, 2.0, 0.0, //
+        0.0, 0.0, 4.0,
+    ));
+    assert_eq!(matrix.get(0, 1), Some(Area::square_meters(2.0)));
+    assert_eq!(matrix.get(3, 0), None);
+    assert_eq!(matrix.trace(), Area::square_meters(7.0));
+    assert_eq!(
+        matrix.diagonal().value_unchecked(),
+        Vector3::new(1.0, 2.0, 4.0)
+    );
+    assert_eq!(
+        matrix.column(1).value_unchecked(),
+        Vector3::new(2.0, 2.0, 0.0)
+    );
+    assert_eq!(matrix.transpose().get(1, 0), Some(Area::square_meters(2.0)));
+    let side = Area::square_meters(2.0);
+    assert_eq!(matrix.determinant(), side * side * side);
+    let product = matrix * matrix.try_inverse().unwrap();
+    assert_eq!(product.value(), Matrix3::identity());
+    let singular = MatrixArea::zero();
+    assert!(singular.try_inverse().is_none());
+}
+
+#[test]
+fn rotations() {
+    let angle = Dimensionless::dimensionless(std::f64::consts::FRAC_PI_2);
+    let rotation = Rotation::from_axis_angle(&Vector3::z_axis(), angle);
+    assert!((rotation.angle().value() - angle.value()).abs() < 1e-10);
+    let x = VectorLength::meters(1.0, 0.0, 0.0);
+    assert_is_close(rotation.rotate(x).in_meters(), Vector3::new(0.0, 1.0, 0.0));
+    let rotated: VectorLength = rotation * x;
+    assert_is_close(rotated.in_meters(), Vector3::new(0.0, 1.0, 0.0));
+    assert_is_close(
+        rotation.inverse().rotate(rotated).in_meters(),
+        x.in_meters(),
+    );
+    let identity = Rotation::identity();
+    assert_eq!(identity.rotate(x), x);
+}
+
+#[test]
+fn debug() {
+    assert_eq!(
+        format!("{:?}", VectorLength::meters(1.0, 2.0, 3.0)),
+        format!("{} m", Vector3::new(1.0, 2.0, 3.0))
+    );
+}
@@ -0,0 +1,135 @@
+use ::diman::dimension;
+use ::diman::unit_system;
+use ::diman::Ratio;
+
+#[dimension]
+pub struct Dimension {
+    pub length: Ratio,
+    pub time: Ratio,
+    pub voltage: Ratio,
+}
+
+unit_system!(
+    Quantity,
+    Dimension,
+    [
+        def Dimensionless = {},
+        unit dimensionless = Dimensionless,
+        def Length = { length: 1 },
+        unit (meters, "m", [kilo]) = Length,
+        def Time = { time: 1 },
+        unit (seconds, "s") = Time,
+        def Frequency = Dimensionless / Time,
+        unit (hertz, "Hz") = 1 / seconds,
+        def Voltage = { voltage: 1 },
+        unit (volts, "V") = Voltage,
+        def VoltageNoise = { voltage: 1, time: 1/2 },
+        unit volts_per_square_root_hertz = VoltageNoise,
+    ]
+);
+
+macro_rules! gen_tests_for_float {
+    ($float_name: ident) => {
+        mod $float_name {
+            use super::super::$float_name::Frequency;
+            use super::super::$float_name::Length;
+            use super::super::$float_name::Time;
+            use super::super::$float_name::Voltage;
+            use super::super::$float_name::VoltageNoise;
+            use super::super::Dimension;
+            use super::super::DynQuantity;
+            use diman::Ratio;
+
+            fn dimension<S>(quantity: impl Into<DynQuantity<S>>) -> Dimension {
+                quantity.into().dimension().clone()
+            }
+
+            #[test]
+            fn sqrt_of_odd_dimension() {
+                let noise = Voltage::volts(3.0) / Frequency::hertz(4.0).sqrt();
+                assert_eq!(noise.value_unchecked(), 1.5);
+                assert_eq!(
+                    dimension(noise),
+                    Dimension {
+                        length: Ratio::int(0),
+                        time: Ratio::new(1, 2),
+                        voltage: Ratio::int(1),
+                    }
+                );
+            }
+
+            #[test]
+            fn fractional_exponents_in_definitions() {
+                assert_eq!(
+                    VoltageNoise::volts_per_square_root_hertz(2.0),
+                    Voltage::volts(2.0) / Frequency::hertz(1.0).sqrt()
+                );
+            }
+
+            #[test]
+            fn cbrt_of_odd_dimension() {
+                let x = Length::meters(8.0).cbrt();
+                assert_eq!(x.value_unchecked(), 2.0);
+                assert_eq!(x.cubed().value_unchecked(), 8.0);
+                assert_eq!(dimension(x.cubed()), dimension(Length::meters(1.0)));
+            }
+
+            #[test]
+            fn rational_powers() {
+                let x = Length::meters(8.0).powr::<2, 3>();
+                assert!((x.value_unchecked() - 4.0).abs() < 1e-5);
+                assert_eq!(
+                    dimension(x),
+                    Dimension {
+                        length: Ratio::new(2, 3),
+                        time: Ratio::int(0),
+                        voltage: Ratio::int(0),
+                    }
+                );
+                let y = x.powr::<3, 2>();
+                assert!((y.value_unchecked() - 8.0).abs() < 1e-5);
+                assert_eq!(dimension(y), dimension(Length::meters(1.0)));
+            }
+
+            #[test]
+            fn display_fractional_exponents() {
+                let x = Voltage::volts(2.0) * Time::seconds(1.0).sqrt();
+                assert_eq!(format!("{}", x), "2 s^1/2 V");
+                assert_eq!(
+                    format!("{:?}", dimension(x)),
+                    "Dimension { length: 0, time: 1/2, voltage: 1 }"
+                );
+            }
+
+            #[test]
+            fn parse_fractional_exponents() {
+                let x: DynQuantity<$float_name> = "2 V s^1/2".parse().unwrap();
+                assert_eq!(x, (Voltage::volts(2.0) * Time::seconds(1.0).sqrt()).into());
+                let x: DynQuantity<$float_name> = "3 km^(1/2)".parse().unwrap();
+                assert!(
+                    (x.clone().value_unchecked() - 3.0 * (1000.0 as $float_name).sqrt()).abs()
+                        < 1e-3
+                );
+                assert_eq!(x.dimension(), &dimension(Length::meters(1.0).sqrt()));
+            }
+        }
+    };
+}
+
+mod tests {
+    #[cfg(feature = "f32")]
+    gen_tests_for_float!(f32);
+
+    #[cfg(feature = "f64")]
+    gen_tests_for_float!(f64);
+
+    #[cfg(all(feature = "serde", feature = "f64"))]
+    #[test]
+    fn serialize_fractional_exponents() {
+        use super::f64::{Time, Voltage};
+        let x = Voltage::volts(2.0) * Time::seconds(4.0).sqrt();
+        assert_eq!(serde_yaml::to_string(&x).unwrap().trim(), "4 s^1/2 V");
+        let y = serde_yaml::from_str(&serde_yaml::to_string(&x).unwrap()).unwrap();
+        assert_eq!(x, y);
+    }
+}
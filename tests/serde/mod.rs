@@ -100,3 +100,35 @@ gen_tests_for_vector_3!(f32, vec3, glam::Vec3, crate::utils::assert_is_close_f32
 
 #[cfg(all(feature = "f64", feature = "glam-dvec3"))]
 gen_tests_for_vector_3!(f64, dvec3, glam::DVec3, crate::utils::assert_is_close_f64);
+
+macro_rules! gen_tests_for_float {
+    ($float_name: ident, $assert_is_close: path) => {
+        mod $float_name {
+            use crate::example_system::$float_name::Information;
+            use crate::example_system::$float_name::Length;
+            use $assert_is_close as assert_is_close;
+
+            #[test]
+            fn deserialize_binary_prefixed_units() {
+                let q: Information = serde_yaml::from_str("2 KiB").unwrap();
+                assert_is_close(q, Information::bytes(2048.0));
+            }
+
+            #[test]
+            fn deserialize_prefixed_units() {
+                let q: Length = serde_yaml::from_str("5.0 km").unwrap();
+                assert_is_close(q, Length::meters(5000.0));
+                let q: Length = serde_yaml::from_str("5.0 mm").unwrap();
+                assert_is_close(q, Length::meters(0.005));
+                let q: Length = serde_yaml::from_str("5.0 µm").unwrap();
+                assert_is_close(q, Length::micrometers(5.0));
+            }
+        }
+    };
+}
+
+#[cfg(feature = "f32")]
+gen_tests_for_float!(f32, crate::utils::assert_is_close_f32);
+
+#[cfg(feature = "f64")]
+gen_tests_for_float!(f64, crate::utils::assert_is_close_f64);
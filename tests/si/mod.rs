@@ -0,0 +1,316 @@
+macro_rules! gen_tests_for_float {
+    ($float_name: ident) => {
+        mod $float_name {
+            use diman::si::$float_name::*;
+            use diman::si::Dimension;
+            use diman::si::Quantity;
+
+            fn assert_is_close<const D: Dimension>(
+                x: Quantity<$float_name, D>,
+                y: Quantity<$float_name, D>,
+            ) {
+                let (x, y) = (x.value_unchecked(), y.value_unchecked());
+                assert!(((x - y) / y).abs() < 1e-6, "{} {}", x, y);
+            }
+
+            #[test]
+            fn base_units() {
+                assert_is_close(Length::kilometers(1.0), Length::meters(1000.0));
+                assert_is_close(Length::nanometers(1.0), Length::meters(1e-9));
+                assert_is_close(Time::milliseconds(1.0), Time::seconds(1e-3));
+                assert_is_close(Mass::kilograms(1.0), Mass::grams(1000.0));
+                assert_is_close(Mass::milligrams(1.0), Mass::kilograms(1e-6));
+                assert_is_close(Temperature::millikelvin(1.0), Temperature::kelvin(1e-3));
+                assert_is_close(Current::milliamperes(1.0), Current::amperes(1e-3));
+                assert_is_close(
+                    AmountOfSubstance::kilomoles(1.0),
+                    AmountOfSubstance::moles(1000.0),
+                );
+                assert_eq!(LuminousIntensity::candelas(2.0).value_unchecked(), 2.0);
+                assert_eq!(Mass::kilograms(2.0).value_unchecked(), 2.0);
+            }
+
+            #[test]
+            fn mechanical_units() {
+                assert_is_close(Frequency::hertz(1.0), 1.0 / Time::seconds(1.0));
+                assert_is_close(
+                    Force::newtons(1.0),
+                    Mass::kilograms(1.0) * Length::meters(1.0)
+                        / (Time::seconds(1.0) * Time::seconds(1.0)),
+                );
+                assert_is_close(
+                    Pressure::pascals(1.0),
+                    Force::newtons(1.0) / Area::square_meters(1.0),
+                );
+                assert_is_close(
+                    Energy::joules(1.0),
+                    Force::newtons(1.0) * Length::meters(1.0),
+                );
+                assert_is_close(Power::watts(1.0), Energy::joules(1.0) / Time::seconds(1.0));
+                assert_is_close(
+                    Acceleration::meters_per_second_squared(1.0),
+                    Velocity::meters_per_second(1.0) / Time::seconds(1.0),
+                );
+                assert_is_close(
+                    Volume::cubic_meters(1.0),
+                    Area::square_meters(1.0) * Length::meters(1.0),
+                );
+            }
+
+            #[test]
+            fn electromagnetic_units() {
+                assert_is_close(
+                    Charge::coulombs(1.0),
+                    Current::amperes(1.0) * Time::seconds(1.0),
+                );
+                assert_is_close(
+                    Voltage::volts(1.0),
+                    Power::watts(1.0) / Current::amperes(1.0),
+                );
+                assert_is_close(
+                    Capacitance::farads(1.0),
+                    Charge::coulombs(1.0) / Voltage::volts(1.0),
+                );
+                assert_is_close(
+                    Resistance::ohms(1.0),
+                    Voltage::volts(1.0) / Current::amperes(1.0),
+                );
+                assert_is_close(Conductance::siemens(1.0), 1.0 / Resistance::ohms(1.0));
+                assert_is_close(
+                    MagneticFlux::webers(1.0),
+                    Voltage::volts(1.0) * Time::seconds(1.0),
+                );
+                assert_is_close(
+                    MagneticFluxDensity::teslas(1.0),
+                    MagneticFlux::webers(1.0) / Area::square_meters(1.0),
+                );
+                assert_is_close(
+                    Inductance::henries(1.0),
+                    MagneticFlux::webers(1.0) / Current::amperes(1.0),
+                );
+                assert_is_close(Resistance::kiloohms(1.0), Resistance::ohms(1000.0));
+            }
+
+            #[test]
+            fn photometric_and_radiological_units() {
+                assert_is_close(
+                    LuminousFlux::lumens(1.0),
+                    LuminousIntensity::candelas(1.0) * SolidAngle::steradians(1.0),
+                );
+                assert_is_close(
+                    Illuminance::lux(1.0),
+                    LuminousFlux::lumens(1.0) / Area::square_meters(1.0),
+                );
+                assert_is_close(Activity::becquerels(1.0), 1.0 / Time::seconds(1.0));
+                assert_is_close(
+                    AbsorbedDose::grays(1.0),
+                    Energy::joules(1.0) / Mass::kilograms(1.0),
+                );
+                assert_is_close(
+                    EquivalentDose::sieverts(1.0),
+                    Energy::joules(1.0) / Mass::kilograms(1.0),
+                );
+                assert_is_close(
+                    CatalyticActivity::katals(1.0),
+                    AmountOfSubstance::moles(1.0) / Time::seconds(1.0),
+                );
+            }
+
+            #[test]
+            fn temperature_units() {
+                assert_is_close(
+                    Temperature::celsius(0.0).absolute(),
+                    Temperature::kelvin(273.15),
+                );
+                assert_is_close(
+                    Temperature::celsius_difference(1.0),
+                    Temperature::kelvin(1.0),
+                );
+            }
+
+            #[test]
+            fn angle_units() {
+                assert_eq!(Angle::radians(1.0).value_unchecked(), 1.0);
+                assert_is_close(
+                    Angle::degrees(180.0),
+                    Angle::radians(std::f64::consts::PI as $float_name),
+                );
+                assert_is_close(Angle::arcminutes(60.0), Angle::degrees(1.0));
+                assert_is_close(Angle::arcseconds(3600.0), Angle::degrees(1.0));
+                assert_eq!(format!("{}", Angle::radians(1.0)), "1");
+            }
+
+            #[test]
+            fn non_si_units() {
+                assert_is_close(Time::minutes(1.0), Time::seconds(60.0));
+                assert_is_close(Time::hours(1.0), Time::seconds(3600.0));
+                assert_is_close(Time::days(1.0), Time::seconds(86400.0));
+                assert_is_close(
+                    Length::astronomical_units(1.0),
+                    Length::meters(1.4959787e11),
+                );
+                assert_is_close(Area::hectares(1.0), Area::square_meters(1e4));
+                assert_is_close(Volume::liters(1.0), Volume::cubic_meters(1e-3));
+                assert_is_close(Volume::milliliters(1.0), Volume::cubic_meters(1e-6));
+                assert_is_close(Mass::tonnes(1.0), Mass::kilograms(1e3));
+                assert_is_close(Mass::daltons(1.0), ATOMIC_MASS_CONSTANT);
+                assert_is_close(
+                    Energy::electronvolts(1.0),
+                    ELEMENTARY_CHARGE * Voltage::volts(1.0),
+                );
+                assert_is_close(Energy::megaelectronvolts(1.0), Energy::electronvolts(1e6));
+            }
+
+            #[test]
+            fn debug_symbols() {
+                assert_eq!(format!("{:?}", Force::newtons(2.0)), "2 N");
+                assert_eq!(format!("{:?}", Resistance::ohms(2.0)), "2 Ω");
+                assert_eq!(format!("{:?}", Frequency::hertz(2.0)), "2 Hz");
+                assert_eq!(format!("{:?}", AbsorbedDose::grays(2.0)), "2 Gy");
+                assert_eq!(format!("{:?}", LuminousIntensity::candelas(2.0)), "2 cd");
+            }
+
+            #[test]
+            fn display_in_units_with_the_same_factor() {
+                let activity = Activity::becquerels(2.0);
+                assert_eq!(format!("{}", activity.display_in_becquerels()), "2 Bq");
+                assert_eq!(format!("{}", activity.display_in_hertz()), "2 Hz");
+                let dose = EquivalentDose::sieverts(2.0);
+                assert_eq!(format!("{}", dose.display_in_sieverts()), "2 Sv");
+                assert_eq!(
+                    format!("{}", Angle::radians(1.0).display_in_radians()),
+                    "1 radians"
+                );
+            }
+
+            #[test]
+            fn from_str() {
+                let x: Pressure = "2 kPa".parse().unwrap();
+                assert_is_close(x, Pressure::pascals(2000.0));
+                let x: Resistance = "3 kΩ".parse().unwrap();
+                assert_is_close(x, Resistance::ohms(3000.0));
+                let x: Energy = "1 MeV".parse().unwrap();
+                assert_is_close(x, Energy::electronvolts(1e6));
+                let x: Velocity = "36 km/h".parse().unwrap();
+                assert_is_close(x, Velocity::meters_per_second(10.0));
+            }
+
+            #[test]
+            fn defining_constants() {
+                assert_is_close(
+                    HYPERFINE_TRANSITION_FREQUENCY_OF_CS,
+                    Frequency::hertz(9192631770.0),
+                );
+                assert_is_close(SPEED_OF_LIGHT, Velocity::meters_per_second(299792458.0));
+                assert_is_close(ELEMENTARY_CHARGE, Charge::coulombs(1.6021766e-19));
+                assert_is_close(
+                    AVOGADRO_CONSTANT * AmountOfSubstance::moles(1.0),
+                    Dimensionless::dimensionless(6.0221408e23),
+                );
+                assert_is_close(
+                    LUMINOUS_EFFICACY * Power::watts(1.0),
+                    LuminousFlux::lumens(683.0),
+                );
+                assert_is_close(
+                    BOLTZMANN_CONSTANT * Temperature::kelvin(1.0),
+                    Energy::joules(1.380649e-23),
+                );
+            }
+
+            #[test]
+            fn derived_constants() {
+                assert_is_close(MOLAR_GAS_CONSTANT, AVOGADRO_CONSTANT * BOLTZMANN_CONSTANT);
+                assert_is_close(FARADAY_CONSTANT, AVOGADRO_CONSTANT * ELEMENTARY_CHARGE);
+                assert_is_close(Mass::daltons(1.0), ATOMIC_MASS_CONSTANT);
+                assert_is_close(
+                    GRAVITATIONAL_CONSTANT * Mass::kilograms(1.0) * Mass::kilograms(1.0)
+                        / Area::square_meters(1.0),
+                    Force::newtons(6.67430e-11),
+                );
+                assert_is_close(
+                    STANDARD_ACCELERATION_OF_GRAVITY * Mass::kilograms(1.0),
+                    Force::newtons(9.80665),
+                );
+                assert_is_close(STANDARD_ATMOSPHERE, Pressure::kilopascals(101.325));
+                assert_is_close(BOHR_RADIUS, Length::meters(5.2917721e-11));
+                assert_is_close(
+                    RYDBERG_CONSTANT * Length::meters(1.0),
+                    Dimensionless::dimensionless(10973731.568160),
+                );
+                assert!(ELECTRON_MASS < PROTON_MASS);
+                assert!(PROTON_MASS < NEUTRON_MASS);
+            }
+        }
+    };
+}
+
+#[cfg(feature = "f32")]
+gen_tests_for_float!(f32);
+
+#[cfg(feature = "f64")]
+gen_tests_for_float!(f64);
+
+#[cfg(feature = "f64")]
+mod codata_relations {
+    use diman::si::f64::*;
+    use std::f64::consts::PI;
+
+    fn assert_is_close_relative(x: f64, y: f64) {
+        assert!(((x - y) / y).abs() < 1e-9, "{} {}", x, y);
+    }
+
+    #[test]
+    fn reduced_planck_constant() {
+        assert_is_close_relative(
+            (PLANCK_CONSTANT / (2.0 * PI)).value_unchecked(),
+            REDUCED_PLANCK_CONSTANT.value_unchecked(),
+        );
+    }
+
+    #[test]
+    fn vacuum_permittivity() {
+        let product: Dimensionless = VACUUM_ELECTRIC_PERMITTIVITY
+            * VACUUM_MAGNETIC_PERMEABILITY
+            * SPEED_OF_LIGHT
+            * SPEED_OF_LIGHT;
+        assert_is_close_relative(product.value_unchecked(), 1.0);
+    }
+
+    #[test]
+    fn fine_structure_constant() {
+        let alpha: Dimensionless = ELEMENTARY_CHARGE * ELEMENTARY_CHARGE
+            / (4.0 * PI * VACUUM_ELECTRIC_PERMITTIVITY * REDUCED_PLANCK_CONSTANT * SPEED_OF_LIGHT);
+        assert_is_close_relative(
+            alpha.value_unchecked(),
+            FINE_STRUCTURE_CONSTANT.value_unchecked(),
+        );
+    }
+
+    #[test]
+    fn stefan_boltzmann_constant() {
+        let k = BOLTZMANN_CONSTANT.value_unchecked();
+        let hbar = REDUCED_PLANCK_CONSTANT.value_unchecked();
+        let c = SPEED_OF_LIGHT.value_unchecked();
+        let sigma = PI * PI * k.powi(4) / (60.0 * hbar.powi(3) * c * c);
+        // The CODATA value is rounded to ten digits.
+        assert!((sigma / STEFAN_BOLTZMANN_CONSTANT.value_unchecked() - 1.0).abs() < 1e-8);
+    }
+
+    #[test]
+    fn bohr_radius() {
+        let a0: Length =
+            REDUCED_PLANCK_CONSTANT / (ELECTRON_MASS * SPEED_OF_LIGHT * FINE_STRUCTURE_CONSTANT);
+        assert_is_close_relative(a0.value_unchecked(), BOHR_RADIUS.value_unchecked());
+    }
+
+    #[test]
+    fn rydberg_constant() {
+        let rydberg =
+            FINE_STRUCTURE_CONSTANT * FINE_STRUCTURE_CONSTANT * ELECTRON_MASS * SPEED_OF_LIGHT
+                / (2.0 * PLANCK_CONSTANT);
+        assert_is_close_relative(
+            rydberg.value_unchecked(),
+            RYDBERG_CONSTANT.value_unchecked(),
+        );
+    }
+}
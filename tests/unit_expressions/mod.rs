@@ -0,0 +1,90 @@
+use ::diman::dimension;
+use ::diman::unit_system;
+
+#[dimension]
+pub struct Dimension {
+    pub length: i32,
+    pub time: i32,
+}
+
+unit_system!(
+    Quantity,
+    Dimension,
+    [
+        def Dimensionless = {},
+        unit dimensionless = Dimensionless,
+        unit radians = Dimensionless,
+        unit degrees = (pi / 180) * radians,
+        unit e_folds = e * dimensionless,
+        def Length = { length: 1 },
+        unit (meters, "m", [kilo]) = Length,
+        unit (astronomical_units, "au") = 149597870700 * meters,
+        unit (parsecs, "pc") = (648000 / pi) * astronomical_units,
+        unit (stretched_meters, "sm") = (1 + 1e-3) * meters,
+        unit (shrunk_meters, "shm") = (1 - 0.25 + 0.5e-1) * meters,
+        def Time = { time: 1 },
+        unit (seconds, "s") = Time,
+        def Frequency = Time^-1,
+        unit (hertz, "Hz") = seconds^-1,
+        def Area = Length^2,
+        unit (square_kilometers, "km^2") = kilometers^2,
+        def Velocity = Length / Time,
+        unit (meters_per_second, "m/s") = meters / seconds,
+        unit (kilometers_per_hour, "km/h") = (1000 / 3600) * meters_per_second,
+        def Acceleration = Length * Frequency^2,
+        constant STANDARD_GRAVITY: Acceleration = 9.80665 * meters / seconds^2,
+        constant HALF_STANDARD_GRAVITY: Acceleration = 0.5 * (9.80665 * meters / seconds) / seconds,
+    ]
+);
+
+#[cfg(feature = "f64")]
+mod tests {
+    use super::f64::{Acceleration, Area, Dimensionless, Frequency, Length, Time, Velocity};
+    use super::f64::{HALF_STANDARD_GRAVITY, STANDARD_GRAVITY};
+
+    fn assert_is_close(x: f64, y: f64) {
+        assert!(((x - y) / y).abs() < 1e-12, "{} {}", x, y);
+    }
+
+    #[test]
+    fn powers() {
+        assert_is_close(Area::square_kilometers(1.0).value_unchecked(), 1e6);
+        let frequency: Frequency = 1.0 / Time::seconds(0.5);
+        assert_is_close(frequency.in_hertz(), 2.0);
+        let acceleration: Acceleration = Length::meters(1.0) * frequency * frequency;
+        assert_is_close(acceleration.value_unchecked(), 4.0);
+    }
+
+    #[test]
+    fn constants_pi_and_e() {
+        assert_is_close(
+            Dimensionless::degrees(180.0).value_unchecked(),
+            std::f64::consts::PI,
+        );
+        assert_is_close(
+            Dimensionless::e_folds(1.0).value_unchecked(),
+            std::f64::consts::E,
+        );
+        assert_is_close(Length::parsecs(1.0).in_meters(), 3.085677581491367e16);
+    }
+
+    #[test]
+    fn sums() {
+        assert_is_close(Length::stretched_meters(1.0).in_meters(), 1.001);
+        assert_is_close(Length::shrunk_meters(1.0).in_meters(), 0.8);
+    }
+
+    #[test]
+    fn parenthesized_divisions() {
+        assert_is_close(
+            Length::parsecs(1.0).in_astronomical_units(),
+            648000.0 / std::f64::consts::PI,
+        );
+        assert_is_close(
+            Velocity::kilometers_per_hour(36.0).in_meters_per_second(),
+            10.0,
+        );
+        assert_is_close(STANDARD_GRAVITY.value_unchecked(), 9.80665);
+        assert_is_close(HALF_STANDARD_GRAVITY.value_unchecked(), 9.80665 / 2.0);
+    }
+}
@@ -0,0 +1,196 @@
+use crate::example_system::parse_unit_expression;
+use crate::example_system::UnitParseErrorKind;
+
+#[test]
+fn parse_unit_expression_simple() {
+    let (dimension, factor) = parse_unit_expression("km s^-1").unwrap();
+    let (velocity_dimension, _) = parse_unit_expression("m/s").unwrap();
+    assert_eq!(dimension, velocity_dimension);
+    assert_eq!(factor, 1000.0);
+}
+
+#[test]
+fn parse_unit_expression_unknown_unit() {
+    let err = parse_unit_expression("km foo^2").unwrap_err();
+    assert_eq!(err.kind, UnitParseErrorKind::UnknownUnit);
+    assert_eq!(err.token, "foo");
+}
+
+#[test]
+fn parse_unit_expression_invalid_exponent() {
+    let err = parse_unit_expression("km s^x").unwrap_err();
+    assert_eq!(err.kind, UnitParseErrorKind::InvalidExponent);
+    assert_eq!(err.token, "x");
+}
+
+#[test]
+fn parse_unit_expression_exponent_overflow() {
+    let err = parse_unit_expression("(m^100000)^100000").unwrap_err();
+    assert_eq!(err.kind, UnitParseErrorKind::InvalidExponent);
+    assert_eq!(err.token, "100000");
+    assert_eq!(err.column, 12);
+    let err = parse_unit_expression("m/m^-2147483648").unwrap_err();
+    assert_eq!(err.kind, UnitParseErrorKind::InvalidExponent);
+    assert_eq!(err.column, 2);
+}
+
+macro_rules! gen_tests_for_float {
+    ($float_name: ident, $assert_is_close: path) => {
+        mod $float_name {
+            use crate::example_system::$float_name::Length;
+            use crate::example_system::$float_name::Velocity;
+            use crate::example_system::UnitParseErrorKind;
+            use $assert_is_close as assert_is_close;
+
+            #[test]
+            fn from_str() {
+                let v: Velocity = "10 km s^-1".parse().unwrap();
+                assert_is_close(v, Velocity::meters_per_second(10000.0));
+                let x: Length = " 2.5 mm ".parse().unwrap();
+                assert_is_close(x, Length::millimeters(2.5));
+            }
+
+            #[test]
+            fn from_str_grammar() {
+                let v: Velocity = "36 km/h".parse().unwrap();
+                assert_is_close(v, Velocity::meters_per_second(10.0));
+                let v: Velocity = "9 km/(s)".parse().unwrap();
+                assert_is_close(v, Velocity::meters_per_second(9000.0));
+            }
+
+            #[test]
+            fn from_str_error_column() {
+                let err = "  10 km/foo".parse::<Velocity>().unwrap_err();
+                assert_eq!(err.kind, UnitParseErrorKind::UnknownUnit);
+                assert_eq!(err.column, 9);
+                let err = " x km".parse::<Length>().unwrap_err();
+                assert_eq!(err.column, 2);
+            }
+
+            #[test]
+            fn from_str_dimension_mismatch() {
+                let err = "10 m".parse::<Velocity>().unwrap_err();
+                assert!(matches!(err.kind, UnitParseErrorKind::DimensionMismatch(_)));
+                assert_eq!(err.token, "m");
+                assert_eq!(err.column, 4);
+                let err = "  10 km/s^2".parse::<Velocity>().unwrap_err();
+                assert!(matches!(err.kind, UnitParseErrorKind::DimensionMismatch(_)));
+                assert_eq!(err.token, "km/s^2");
+                assert_eq!(err.column, 6);
+                let err = " 10".parse::<Velocity>().unwrap_err();
+                assert!(matches!(err.kind, UnitParseErrorKind::DimensionMismatch(_)));
+                assert_eq!(err.token, "10");
+                assert_eq!(err.column, 2);
+            }
+
+            #[test]
+            fn from_str_invalid_number() {
+                let err = "ten m".parse::<Length>().unwrap_err();
+                assert_eq!(err.kind, UnitParseErrorKind::InvalidNumber);
+                assert_eq!(err.token, "ten");
+            }
+
+            #[test]
+            fn from_str_empty() {
+                let err = "  ".parse::<Length>().unwrap_err();
+                assert_eq!(err.kind, UnitParseErrorKind::Empty);
+            }
+        }
+    };
+}
+
+#[cfg(feature = "f32")]
+gen_tests_for_float!(f32, crate::utils::assert_is_close_f32);
+
+#[cfg(feature = "f64")]
+gen_tests_for_float!(f64, crate::utils::assert_is_close_f64);
+
+#[test]
+fn parse_unit_expression_operators() {
+    let (velocity, _) = parse_unit_expression("m s^-1").unwrap();
+    let (acceleration, factor) = parse_unit_expression("km/s^2").unwrap();
+    assert_eq!(factor, 1000.0);
+    assert_eq!(
+        acceleration,
+        velocity
+            .clone()
+            .dimension_div(parse_unit_expression("s").unwrap().0)
+    );
+    assert_eq!(parse_unit_expression("km/s").unwrap().0, velocity);
+    assert_eq!(parse_unit_expression("km * s^-1").unwrap().0, velocity);
+    assert_eq!(parse_unit_expression("m/(s)").unwrap().0, velocity);
+    let (energy, factor) = parse_unit_expression("kg*m^2/s^2").unwrap();
+    assert_eq!(factor, 1.0);
+    assert_eq!(energy, parse_unit_expression("J").unwrap().0);
+    let (energy_per_area, factor) = parse_unit_expression("J/(m^2 g)").unwrap();
+    assert_eq!(factor, 1000.0);
+    assert_eq!(
+        energy_per_area,
+        parse_unit_expression("J m^-2 kg^-1").unwrap().0
+    );
+}
+
+#[test]
+fn parse_unit_expression_unicode_exponents() {
+    assert_eq!(
+        parse_unit_expression("m²").unwrap(),
+        parse_unit_expression("m^2").unwrap()
+    );
+    assert_eq!(
+        parse_unit_expression("s⁻¹").unwrap(),
+        parse_unit_expression("s^-1").unwrap()
+    );
+    assert_eq!(
+        parse_unit_expression("m·s⁻²").unwrap(),
+        parse_unit_expression("m/s^2").unwrap()
+    );
+}
+
+#[test]
+fn parse_unit_expression_fractional_exponents() {
+    let (dimension, factor) = parse_unit_expression("(km^2)^(1/2)").unwrap();
+    assert_eq!(dimension, parse_unit_expression("m").unwrap().0);
+    assert!((factor - 1000.0).abs() < 1e-9);
+    assert_eq!(
+        parse_unit_expression("(m^4)^(1/2)").unwrap(),
+        parse_unit_expression("m^2").unwrap()
+    );
+    let err = parse_unit_expression("km^(1/2)").unwrap_err();
+    assert_eq!(err.kind, UnitParseErrorKind::FractionalDimension);
+    assert_eq!(err.column, 1);
+}
+
+#[test]
+fn parse_unit_expression_unparenthesized_fractional_exponents() {
+    assert_eq!(
+        parse_unit_expression("m^4/2 s^-2/2").unwrap(),
+        parse_unit_expression("m^2 s^-1").unwrap()
+    );
+    assert_eq!(
+        parse_unit_expression("m^2/2/s").unwrap(),
+        parse_unit_expression("m/s").unwrap()
+    );
+    let err = parse_unit_expression("km^1/2").unwrap_err();
+    assert_eq!(err.kind, UnitParseErrorKind::FractionalDimension);
+    let err = parse_unit_expression("km^1/0").unwrap_err();
+    assert_eq!(err.kind, UnitParseErrorKind::InvalidExponent);
+}
+
+#[test]
+fn parse_unit_expression_error_columns() {
+    let err = parse_unit_expression("m/(s km").unwrap_err();
+    assert_eq!(err.kind, UnitParseErrorKind::InvalidSyntax);
+    assert_eq!(err.column, 3);
+    let err = parse_unit_expression("m / foo").unwrap_err();
+    assert_eq!(err.kind, UnitParseErrorKind::UnknownUnit);
+    assert_eq!(err.column, 5);
+    let err = parse_unit_expression("m^x").unwrap_err();
+    assert_eq!(err.kind, UnitParseErrorKind::InvalidExponent);
+    assert_eq!(err.column, 3);
+    let err = parse_unit_expression("m )").unwrap_err();
+    assert_eq!(err.kind, UnitParseErrorKind::InvalidSyntax);
+    assert_eq!(err.column, 3);
+    let err = parse_unit_expression("m /").unwrap_err();
+    assert_eq!(err.kind, UnitParseErrorKind::InvalidSyntax);
+    assert_eq!(err.column, 4);
+}
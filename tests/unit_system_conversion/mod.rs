@@ -0,0 +1,152 @@
+pub mod mks {
+    use ::diman::dimension;
+    use ::diman::unit_system;
+
+    #[dimension]
+    pub struct Dimension {
+        pub length: i32,
+        pub time: i32,
+        pub mass: i32,
+        pub current: i32,
+    }
+
+    unit_system!(
+        Quantity,
+        Dimension,
+        [
+            def Length = { length: 1 },
+            unit (meters, "m") = Length,
+            def Time = { time: 1 },
+            unit (seconds, "s") = Time,
+            def Mass = { mass: 1 },
+            unit (kilograms, "kg") = Mass,
+            def Current = { current: 1 },
+            unit (amperes, "A") = Current,
+            def Velocity = Length / Time,
+            unit (meters_per_second, "m/s") = meters / seconds,
+            def Energy = Mass * Velocity * Velocity,
+            unit (joules, "J") = kilograms * meters_per_second * meters_per_second,
+        ]
+    );
+}
+
+pub mod cgs {
+    use ::diman::dimension;
+    use ::diman::unit_system;
+    use ::diman::Ratio;
+
+    #[dimension]
+    pub struct Dimension {
+        pub distance: Ratio,
+        pub duration: Ratio,
+        pub weight: Ratio,
+        pub temperature: Ratio,
+    }
+
+    unit_system!(
+        Quantity,
+        Dimension: Ratio,
+        [
+            def Length = { distance: 1 },
+            unit (centimeters, "cm") = Length,
+            def Time = { duration: 1 },
+            unit (seconds, "s") = Time,
+            def Mass = { weight: 1 },
+            unit (grams, "g") = Mass,
+            def Velocity = Length / Time,
+            unit (centimeters_per_second, "cm/s") = centimeters / seconds,
+            def Energy = Mass * Velocity * Velocity,
+            unit (ergs, "erg") = grams * centimeters_per_second * centimeters_per_second,
+        ]
+    );
+}
+
+diman::unit_system_conversion!(
+    mks::Quantity<mks::Dimension>,
+    cgs::Quantity<cgs::Dimension>,
+    {
+        length => 100 * distance,
+        mass => 1000 * weight,
+        time => duration,
+    }
+);
+
+diman::unit_system_conversion!(
+    cgs::Quantity<cgs::Dimension>,
+    mks::Quantity<mks::Dimension>,
+    {
+        distance => 0.01 * length,
+        weight => 0.001 * mass,
+        duration => time,
+    }
+);
+
+macro_rules! gen_tests_for_float {
+    ($float_name: ident) => {
+        mod $float_name {
+            use super::super::cgs;
+            use super::super::mks;
+
+            fn assert_is_close_relative(x: $float_name, y: $float_name) {
+                assert!(((x - y) / y).abs() < 1e-6, "{} {}", x, y);
+            }
+
+            #[test]
+            fn base_dimensions() {
+                let length: cgs::$float_name::Length = mks::$float_name::Length::meters(2.0).into();
+                assert_is_close_relative(length.value_unchecked(), 200.0);
+                let mass: cgs::$float_name::Mass = mks::$float_name::Mass::kilograms(2.0).into();
+                assert_is_close_relative(mass.value_unchecked(), 2000.0);
+                let time: cgs::$float_name::Time = mks::$float_name::Time::seconds(2.0).into();
+                assert_eq!(time.value_unchecked(), 2.0);
+            }
+
+            #[test]
+            fn scale_is_applied_in_a_single_step() {
+                let value: $float_name = 0.3;
+                let length: cgs::$float_name::Length = mks::$float_name::Length::meters(value).into();
+                assert_eq!(length.value_unchecked(), value * 100.0);
+                let velocity: mks::$float_name::Velocity =
+                    cgs::$float_name::Velocity::centimeters_per_second(value).into();
+                assert_eq!(velocity.value_unchecked(), value * 0.01);
+            }
+
+            #[test]
+            fn derived_dimensions() {
+                let energy: cgs::$float_name::Energy = mks::$float_name::Energy::joules(1.0).into();
+                assert_is_close_relative(energy.value_unchecked(), 1e7);
+                let velocity: mks::$float_name::Velocity =
+                    cgs::$float_name::Velocity::centimeters_per_second(100.0).into();
+                assert_is_close_relative(velocity.value_unchecked(), 1.0);
+            }
+
+            #[test]
+            fn integer_to_ratio_exponents() {
+                let root = mks::$float_name::Length::meters(4.0).powi::<3>()
+                    * mks::$float_name::Mass::kilograms(1.0);
+                let converted: cgs::Quantity<
+                    $float_name,
+                    { cgs::Dimension::none().with_distance(3).with_weight(1) },
+                > = root.into();
+                let root = converted.sqrt();
+                assert_is_close_relative(root.value_unchecked(), (64.0e9 as $float_name).sqrt());
+            }
+
+            #[test]
+            fn round_trip() {
+                let energy = mks::$float_name::Energy::joules(3.0);
+                let converted: cgs::$float_name::Energy = energy.into();
+                let back: mks::$float_name::Energy = converted.into();
+                assert_is_close_relative(back.value_unchecked(), 3.0);
+            }
+        }
+    };
+}
+
+mod tests {
+    #[cfg(feature = "f32")]
+    gen_tests_for_float!(f32);
+
+    #[cfg(feature = "f64")]
+    gen_tests_for_float!(f64);
+}
@@ -0,0 +1,194 @@
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+use ::diman::dimension;
+use ::diman::unit_system;
+use ::diman::DebugStorageType;
+use ::diman::StorageType;
+
+/// A fixed point number with three decimal places.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Default)]
+pub struct Fixed(i64);
+
+impl Fixed {
+    pub fn from_f64(val: f64) -> Self {
+        Self((val * 1000.0).round() as i64)
+    }
+
+    pub fn to_f64(self) -> f64 {
+        self.0 as f64 / 1000.0
+    }
+}
+
+impl Add for Fixed {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Fixed {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl Mul for Fixed {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self(self.0 * rhs.0 / 1000)
+    }
+}
+
+impl Div for Fixed {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Self(self.0 * 1000 / rhs.0)
+    }
+}
+
+impl Neg for Fixed {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl AddAssign for Fixed {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl SubAssign for Fixed {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl std::fmt::Display for Fixed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.to_f64().fmt(f)
+    }
+}
+
+impl StorageType for Fixed {
+    fn mul_f64(&self, val: f64) -> Self {
+        Self::from_f64(self.to_f64() * val)
+    }
+
+    fn div_f64(&self, val: f64) -> Self {
+        Self::from_f64(self.to_f64() / val)
+    }
+
+    fn add_f64(&self, val: f64) -> Self {
+        Self::from_f64(self.to_f64() + val)
+    }
+
+    fn sub_f64(&self, val: f64) -> Self {
+        Self::from_f64(self.to_f64() - val)
+    }
+}
+
+impl DebugStorageType for Fixed {
+    fn representative_value(&self) -> f64 {
+        self.to_f64()
+    }
+}
+
+#[dimension]
+pub struct Dimension {
+    pub length: i32,
+    pub time: i32,
+    pub temperature: i32,
+}
+
+unit_system!(
+    Quantity,
+    Dimension,
+    [
+        def Dimensionless = {},
+        unit dimensionless = Dimensionless,
+        def Length = { length: 1 },
+        unit (meters, "m", [kilo, milli]) = Length,
+        def Time = { time: 1 },
+        unit (seconds, "s") = Time,
+        unit (minutes, "min") = 60 * seconds,
+        def Velocity = Length / Time,
+        def Area = Length * Length,
+        def Temperature = { temperature: 1 },
+        unit (kelvin, "K") = Temperature,
+        unit (celsius, "°C") = kelvin + 273.15,
+        storage fixed = crate::user_storage_types::Fixed,
+    ]
+);
+
+mod tests {
+    use super::fixed::{Area, Dimensionless, Length, Temperature, Time, Velocity};
+    use super::{Fixed, QuantityPoint};
+
+    fn fixed(val: f64) -> Fixed {
+        Fixed::from_f64(val)
+    }
+
+    #[test]
+    fn constructors() {
+        assert_eq!(Length::meters(fixed(1.5)).value_unchecked(), fixed(1.5));
+        assert_eq!(
+            Length::kilometers(fixed(1.5)).value_unchecked(),
+            fixed(1500.0)
+        );
+        assert_eq!(Time::minutes(fixed(0.5)), Time::seconds(fixed(30.0)));
+        assert_eq!(
+            Temperature::celsius(fixed(1.0))
+                .absolute()
+                .value_unchecked(),
+            fixed(274.15)
+        );
+        assert_eq!(
+            Temperature::celsius_difference(fixed(1.0)).value_unchecked(),
+            fixed(1.0)
+        );
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Length::kilometers(fixed(1.5)).in_meters(), fixed(1500.0));
+        assert_eq!(Length::meters(fixed(1.5)).in_millimeters(), fixed(1500.0));
+        assert_eq!(
+            QuantityPoint::from_absolute(Temperature::kelvin(fixed(300.0))).in_celsius(),
+            fixed(26.85)
+        );
+    }
+
+    #[test]
+    fn arithmetic() {
+        let length = Length::meters(fixed(1.5)) + Length::millimeters(fixed(500.0));
+        assert_eq!(length, Length::meters(fixed(2.0)));
+        assert_eq!(
+            length - Length::meters(fixed(0.5)),
+            Length::meters(fixed(1.5))
+        );
+        let area: Area = length * Length::meters(fixed(3.0));
+        assert_eq!(area.value_unchecked(), fixed(6.0));
+        let velocity: Velocity = length / Time::seconds(fixed(4.0));
+        assert_eq!(velocity.value_unchecked(), fixed(0.5));
+        assert_eq!(length * fixed(2.0), Length::meters(fixed(4.0)));
+        assert_eq!(-length, Length::meters(fixed(-2.0)));
+        let ratio: Dimensionless = length / Length::meters(fixed(4.0));
+        assert_eq!(ratio.value(), fixed(0.5));
+        let total: Length = [length, length].into_iter().sum();
+        assert_eq!(total, Length::meters(fixed(4.0)));
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(format!("{:?}", Length::meters(fixed(1.5))), "1.5 m");
+        assert_eq!(format!("{}", Length::kilometers(fixed(2.0))), "2 km");
+    }
+}